@@ -0,0 +1,43 @@
+//! Bakes toolchain and source revision into the binary for the environment
+//! fingerprint (see `src/environment.rs`).
+
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=QH_RUSTC_VERSION={}", rustc_version);
+
+    let git_commit = command_output("git", &["rev-parse", "--short=12", "HEAD"])
+        .map(|commit| {
+            let dirty = command_output("git", &["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty());
+            if dirty {
+                format!("{}-dirty", commit)
+            } else {
+                commit
+            }
+        })
+        .unwrap_or_default();
+    println!("cargo:rustc-env=QH_GIT_COMMIT={}", git_commit);
+
+    let profile = std::env::var("PROFILE").unwrap_or_else(|_| "unknown".to_string());
+    println!("cargo:rustc-env=QH_BUILD_PROFILE={}", profile);
+
+    // Only watch git metadata that exists; a missing path would rerun on every build
+    for path in [".git/HEAD", ".git/index", ".git/refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+    println!("cargo:rerun-if-changed=build.rs");
+}
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
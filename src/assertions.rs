@@ -0,0 +1,242 @@
+//! Performance assertions for CI gating
+//!
+//! Thresholds a run must meet: minimum sequential TPS, minimum speedup at a
+//! given segment count, maximum p99 verify latency. Each assertion is checked
+//! against every matching result row and judged on the worst one, so a
+//! multi-scheme or multi-tx-count run passes only if all of them do.
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::report::StrategyRow;
+
+/// Thresholds to check after a run; unset ones are skipped
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Assertions {
+    /// Every sequential baseline must reach this TPS
+    pub min_sequential_tps: Option<f64>,
+    /// Speedup over sequential required at specific segment counts
+    pub min_speedup: Vec<SpeedupAssertion>,
+    /// No row's p99 verify latency may exceed this, in milliseconds
+    pub max_p99_ms: Option<f64>,
+}
+
+/// Minimum speedup of the torus sweep at `segments` segments
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpeedupAssertion {
+    pub segments: usize,
+    pub speedup: f64,
+}
+
+impl FromStr for SpeedupAssertion {
+    type Err = String;
+
+    /// Parse `SPEEDUP@SEGMENTS`, e.g. `4.0@64`
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (speedup, segments) = s
+            .split_once('@')
+            .ok_or_else(|| format!("expected SPEEDUP@SEGMENTS like 4.0@64, got '{}'", s))?;
+        Ok(Self {
+            speedup: speedup.trim().parse().map_err(|e| format!("invalid speedup '{}': {}", speedup, e))?,
+            segments: segments.trim().parse().map_err(|e| format!("invalid segment count '{}': {}", segments, e))?,
+        })
+    }
+}
+
+/// Outcome of one assertion
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AssertionResult {
+    /// Stable identifier, e.g. "min_speedup@64"
+    pub name: String,
+    /// Human-readable requirement, e.g. "speedup at 64 segments ≥ 4.00×"
+    pub requirement: String,
+    /// Worst value observed; `None` if nothing matching was measured
+    pub actual: Option<f64>,
+    /// Row that produced `actual`
+    pub worst_row: Option<String>,
+    pub passed: bool,
+}
+
+impl Assertions {
+    pub fn is_empty(&self) -> bool {
+        self.min_sequential_tps.is_none() && self.min_speedup.is_empty() && self.max_p99_ms.is_none()
+    }
+
+    /// Check every assertion against `rows`
+    pub fn evaluate(&self, rows: &[StrategyRow]) -> Vec<AssertionResult> {
+        let mut results = Vec::new();
+
+        if let Some(min) = self.min_sequential_tps {
+            let worst = worst_by(rows.iter().filter(|r| r.kind == "baseline"), |r| r.tps.mean, f64::lt);
+            results.push(check(
+                "min_sequential_tps".to_string(),
+                format!("sequential TPS ≥ {:.2}", min),
+                worst,
+                |actual| actual >= min,
+            ));
+        }
+
+        for assertion in &self.min_speedup {
+            let matching = rows
+                .iter()
+                .filter(|r| r.kind == "sweep" && r.segments == Some(assertion.segments));
+            results.push(check(
+                format!("min_speedup@{}", assertion.segments),
+                format!("speedup at {} segments ≥ {:.2}×", assertion.segments, assertion.speedup),
+                worst_by(matching, |r| r.speedup, f64::lt),
+                |actual| actual >= assertion.speedup,
+            ));
+        }
+
+        if let Some(max) = self.max_p99_ms {
+            results.push(check(
+                "max_p99_ms".to_string(),
+                format!("p99 verify latency ≤ {:.3} ms", max),
+                worst_by(rows.iter(), |r| r.latency.p99_ms, f64::gt),
+                |actual| actual <= max,
+            ));
+        }
+
+        results
+    }
+}
+
+/// The row whose `value` is worst under `worse` (e.g. `f64::lt` for lowest)
+fn worst_by<'a>(
+    rows: impl Iterator<Item = &'a StrategyRow>,
+    value: impl Fn(&StrategyRow) -> f64,
+    worse: impl Fn(&f64, &f64) -> bool,
+) -> Option<(f64, String)> {
+    rows.fold(None, |worst, row| {
+        let v = value(row);
+        match worst {
+            Some((w, _)) if !worse(&v, &w) => worst,
+            _ => Some((v, format!("{} {} {} ×{}", row.scheme, row.path, row.strategy, row.tx_count))),
+        }
+    })
+}
+
+fn check(
+    name: String,
+    requirement: String,
+    worst: Option<(f64, String)>,
+    passes: impl Fn(f64) -> bool,
+) -> AssertionResult {
+    let (actual, worst_row) = worst.unzip();
+    AssertionResult {
+        name,
+        requirement,
+        passed: actual.is_some_and(passes),
+        actual,
+        worst_row,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{LatencyRow, ThroughputStats};
+
+    fn row(kind: &str, segments: Option<usize>, tx_count: usize, tps: f64, speedup: f64, p99_ms: f64) -> StrategyRow {
+        StrategyRow {
+            suite: None,
+            scheme: "shake-128f".to_string(),
+            path: "detached".to_string(),
+            tx_count,
+            payload_bytes: 80,
+            kind: kind.to_string(),
+            strategy: segments.map_or("Sequential".to_string(), |n| format!("{} segments", n)),
+            segments,
+            threads: None,
+            verified: tx_count,
+            total: tx_count,
+            tps: ThroughputStats {
+                mean: tps,
+                median: tps,
+                stddev: 0.0,
+                min: tps,
+                max: tps,
+                ci95: 0.0,
+                samples: vec![tps],
+                outliers: Vec::new(),
+            },
+            speedup,
+            latency: LatencyRow { p99_ms, ..LatencyRow::default() },
+            mesh: None,
+        }
+    }
+
+    fn rows() -> Vec<StrategyRow> {
+        vec![
+            row("baseline", None, 100, 1000.0, 1.0, 2.0),
+            row("sweep", Some(4), 100, 3500.0, 3.5, 3.0),
+            row("baseline", None, 1000, 900.0, 1.0, 2.5),
+            row("sweep", Some(4), 1000, 2700.0, 3.0, 4.0),
+        ]
+    }
+
+    #[test]
+    fn parses_speedup_at_segments() {
+        let parsed: SpeedupAssertion = " 4.0 @ 64 ".parse().unwrap();
+        assert_eq!(parsed, SpeedupAssertion { segments: 64, speedup: 4.0 });
+        assert!("4.0".parse::<SpeedupAssertion>().is_err());
+        assert!("fast@64".parse::<SpeedupAssertion>().is_err());
+        assert!("4.0@many".parse::<SpeedupAssertion>().is_err());
+        assert!("4.0@-1".parse::<SpeedupAssertion>().is_err());
+    }
+
+    #[test]
+    fn judges_each_assertion_on_its_worst_row() {
+        let assertions = Assertions {
+            min_sequential_tps: Some(800.0),
+            min_speedup: vec![SpeedupAssertion { segments: 4, speedup: 3.0 }],
+            max_p99_ms: Some(4.0),
+        };
+        let results = assertions.evaluate(&rows());
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.passed), "{:?}", results);
+
+        assert_eq!(results[0].actual, Some(900.0));
+        assert_eq!(results[0].worst_row.as_deref(), Some("shake-128f detached Sequential ×1000"));
+        assert_eq!(results[1].name, "min_speedup@4");
+        assert_eq!(results[1].actual, Some(3.0));
+        assert_eq!(results[1].worst_row.as_deref(), Some("shake-128f detached 4 segments ×1000"));
+        assert_eq!(results[2].actual, Some(4.0));
+    }
+
+    #[test]
+    fn fails_when_the_worst_row_misses() {
+        let assertions = Assertions {
+            min_sequential_tps: Some(950.0),
+            min_speedup: vec![SpeedupAssertion { segments: 4, speedup: 3.2 }],
+            max_p99_ms: Some(3.5),
+        };
+        let results = assertions.evaluate(&rows());
+        assert!(results.iter().all(|r| !r.passed), "{:?}", results);
+        assert_eq!(results[2].actual, Some(4.0));
+    }
+
+    #[test]
+    fn fails_when_no_row_matches() {
+        let assertions = Assertions {
+            min_speedup: vec![SpeedupAssertion { segments: 64, speedup: 1.0 }],
+            ..Assertions::default()
+        };
+        let results = assertions.evaluate(&rows());
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].passed);
+        assert_eq!(results[0].actual, None);
+        assert_eq!(results[0].worst_row, None);
+
+        let results = Assertions { min_sequential_tps: Some(1.0), ..Assertions::default() }.evaluate(&[]);
+        assert!(!results[0].passed);
+    }
+
+    #[test]
+    fn unset_assertions_check_nothing() {
+        assert!(Assertions::default().is_empty());
+        assert!(Assertions::default().evaluate(&rows()).is_empty());
+    }
+}
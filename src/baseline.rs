@@ -0,0 +1,208 @@
+//! Stored baselines and regression comparison
+//!
+//! `--save-baseline <name>` stores a run's [`BenchmarkReport`] as
+//! `<dir>/<name>.json`. `--compare <old> <new>` loads two reports (baseline
+//! names or paths), matches strategy rows by suite, scheme, path, kind,
+//! strategy, tx count and payload size, and flags rows whose mean TPS dropped by more than a threshold.
+//! Reports written before scenario suites existed have no suite or payload
+//! size on their rows; those match a row of any suite and payload size.
+
+use std::path::{Path, PathBuf};
+
+use crate::report::{BenchmarkReport, StrategyRow};
+use crate::stats::{differs_significantly, Stats};
+
+/// Directory of named baseline reports
+pub struct BaselineStore {
+    dir: PathBuf,
+}
+
+impl BaselineStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+
+    /// Store `report` under `name`, replacing any previous baseline of that name
+    pub fn save(&self, name: &str, report: &BenchmarkReport) -> Result<PathBuf, String> {
+        if name.is_empty() || name.contains(['/', '\\']) {
+            return Err(format!("invalid baseline name '{}'", name));
+        }
+        std::fs::create_dir_all(&self.dir).map_err(|e| format!("{}: {}", self.dir.display(), e))?;
+        let path = self.path(name);
+        std::fs::write(&path, report.to_json() + "\n").map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    /// Load a baseline by name, or any report file by path
+    pub fn load(&self, name_or_path: &str) -> Result<BenchmarkReport, String> {
+        let as_path = Path::new(name_or_path);
+        let path = if as_path.is_file() {
+            as_path.to_path_buf()
+        } else {
+            self.path(name_or_path)
+        };
+        let json = std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        BenchmarkReport::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+/// Identity of a result row across runs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RowKey {
+    pub suite: Option<String>,
+    pub scheme: String,
+    pub path: String,
+    /// Sweep and comparison rows can share a strategy label
+    pub kind: String,
+    pub strategy: String,
+    pub tx_count: usize,
+    pub payload_bytes: usize,
+}
+
+impl From<&StrategyRow> for RowKey {
+    fn from(row: &StrategyRow) -> Self {
+        Self {
+            suite: row.suite.clone(),
+            scheme: row.scheme.clone(),
+            path: row.path.clone(),
+            kind: row.kind.clone(),
+            strategy: row.strategy.clone(),
+            tx_count: row.tx_count,
+            payload_bytes: row.payload_bytes,
+        }
+    }
+}
+
+impl RowKey {
+    /// Same row, where a missing suite or a payload size of 0 (a report from before suites) matches any
+    pub fn matches(&self, other: &RowKey) -> bool {
+        let suites_match = match (&self.suite, &other.suite) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        let payloads_match =
+            self.payload_bytes == other.payload_bytes || self.payload_bytes == 0 || other.payload_bytes == 0;
+        suites_match
+            && payloads_match
+            && self.scheme == other.scheme
+            && self.path == other.path
+            && self.kind == other.kind
+            && self.strategy == other.strategy
+            && self.tx_count == other.tx_count
+    }
+}
+
+impl std::fmt::Display for RowKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(suite) = &self.suite {
+            write!(f, "[{}] ", suite)?;
+        }
+        write!(
+            f,
+            "{} {} {} {} ×{} ({}-byte payload)",
+            self.scheme, self.path, self.kind, self.strategy, self.tx_count, self.payload_bytes
+        )
+    }
+}
+
+/// One row present in both reports
+pub struct RowDelta {
+    pub key: RowKey,
+    pub old_tps: f64,
+    pub new_tps: f64,
+    /// Change in mean TPS, in percent of the old value; `None` when the old TPS was 0
+    pub delta_percent: Option<f64>,
+    /// Welch's t-test at 95%; `None` when a side has a single run or the rows aren't comparable
+    pub significant: Option<bool>,
+    /// Dropped by more than the threshold, and not explained by noise
+    pub regressed: bool,
+}
+
+pub struct Comparison {
+    pub rows: Vec<RowDelta>,
+    /// Rows only in the old report
+    pub missing: Vec<RowKey>,
+    /// Rows only in the new report
+    pub added: Vec<RowKey>,
+    pub threshold_percent: f64,
+}
+
+impl Comparison {
+    pub fn regressions(&self) -> usize {
+        self.rows.iter().filter(|r| r.regressed).count()
+    }
+}
+
+/// Compare `new` against `old`, flagging TPS drops larger than `threshold_percent`
+///
+/// A drop only counts as a regression if it is significant, or if a side has
+/// too few runs to tell (a single run can't be shown to be noise).
+pub fn compare(old: &BenchmarkReport, new: &BenchmarkReport, threshold_percent: f64) -> Comparison {
+    let mut rows = Vec::new();
+    let mut missing = Vec::new();
+    for old_row in &old.results {
+        let key = RowKey::from(old_row);
+        let Some(new_row) = new.results.iter().find(|r| key.matches(&RowKey::from(*r))) else {
+            missing.push(key);
+            continue;
+        };
+        let (old_tps, new_tps) = (old_row.tps.mean, new_row.tps.mean);
+        // A stored run with no throughput (e.g. `-t 0`) gives nothing to compare against
+        let delta_percent = (old_tps > 0.0).then(|| (new_tps - old_tps) / old_tps * 100.0);
+        let significant = match (delta_percent, old_row.tps.samples.as_slice(), new_row.tps.samples.as_slice()) {
+            (None, ..) | (_, [], _) | (_, _, []) => None,
+            (_, a, b) => differs_significantly(&Stats::from_samples(a.to_vec()), &Stats::from_samples(b.to_vec())),
+        };
+        rows.push(RowDelta {
+            key,
+            old_tps,
+            new_tps,
+            delta_percent,
+            significant,
+            regressed: delta_percent.is_some_and(|d| d < -threshold_percent) && significant != Some(false),
+        });
+    }
+    let added = new
+        .results
+        .iter()
+        .map(RowKey::from)
+        .filter(|key| !old.results.iter().any(|r| RowKey::from(r).matches(key)))
+        .collect();
+
+    Comparison {
+        rows,
+        missing,
+        added,
+        threshold_percent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(suite: Option<&str>, payload_bytes: usize) -> RowKey {
+        RowKey {
+            suite: suite.map(str::to_string),
+            scheme: "shake-128f".to_string(),
+            path: "detached".to_string(),
+            kind: "strategy".to_string(),
+            strategy: "torus".to_string(),
+            tx_count: 100,
+            payload_bytes,
+        }
+    }
+
+    #[test]
+    fn rows_from_before_suites_match_any_suite_and_payload() {
+        assert!(key(None, 0).matches(&key(Some("quick"), 80)));
+        assert!(key(Some("quick"), 80).matches(&key(None, 0)));
+        assert!(key(Some("quick"), 80).matches(&key(Some("quick"), 80)));
+        assert!(!key(Some("quick"), 80).matches(&key(Some("full"), 80)));
+        assert!(!key(Some("quick"), 80).matches(&key(Some("quick"), 256)));
+    }
+}
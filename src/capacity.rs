@@ -0,0 +1,355 @@
+//! Capacity projection
+//!
+//! Turns a measured verify rate into a projected network TPS for a described
+//! validator set. The per-core rate is the one-thread throughput of a
+//! scheme row; the scaling curve is the row's USL or Amdahl fit from
+//! `--thread-sweep`, or linear scaling (an upper bound) without one. Each
+//! `--validator-spec` gives a validator class: count, cores, block time and
+//! block weight (seconds of each block that may be spent verifying).
+//!
+//! Every validator imports every block, so verification is replicated, not
+//! shared: validators add no throughput and the slowest class bounds the
+//! network. The projection lists the assumptions it was made under.
+
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::report::SchemeRow;
+use crate::scalability::Scalability;
+
+/// Substrate's default slot duration
+pub const DEFAULT_BLOCK_TIME_SECS: f64 = 6.0;
+/// Substrate's default maximum block weight: 2 s of reference execution time
+pub const DEFAULT_BLOCK_WEIGHT_SECS: f64 = 2.0;
+
+/// One class of validator in the projected network
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatorSpec {
+    pub count: usize,
+    pub cores: usize,
+    pub block_time_secs: f64,
+    /// Seconds of each block available for verifying transactions
+    pub block_weight_secs: f64,
+}
+
+impl ValidatorSpec {
+    /// One validator with `cores` cores and Substrate's default block parameters
+    pub fn with_cores(cores: usize) -> Self {
+        Self {
+            count: 1,
+            cores,
+            block_time_secs: DEFAULT_BLOCK_TIME_SECS,
+            block_weight_secs: DEFAULT_BLOCK_WEIGHT_SECS,
+        }
+    }
+}
+
+impl FromStr for ValidatorSpec {
+    type Err = String;
+
+    /// Parse `cores=16[,count=3][,block_time=6][,block_weight=2]`
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut count = 1;
+        let mut cores = None;
+        let mut block_time_secs = DEFAULT_BLOCK_TIME_SECS;
+        let mut block_weight_secs = DEFAULT_BLOCK_WEIGHT_SECS;
+        for field in s.split(',') {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| format!("expected KEY=VALUE in validator spec '{}', got '{}'", s, field))?;
+            let value = value.trim();
+            let invalid = |e: &dyn std::fmt::Display| format!("invalid {} '{}': {}", key.trim(), value, e);
+            match key.trim() {
+                "count" => count = value.parse().map_err(|e| invalid(&e))?,
+                "cores" => cores = Some(value.parse().map_err(|e| invalid(&e))?),
+                "block_time" => block_time_secs = value.trim_end_matches('s').parse().map_err(|e| invalid(&e))?,
+                "block_weight" => block_weight_secs = value.trim_end_matches('s').parse().map_err(|e| invalid(&e))?,
+                other => {
+                    return Err(format!(
+                        "unknown validator spec key '{}' (expected count, cores, block_time, block_weight)",
+                        other
+                    ))
+                }
+            }
+        }
+        let cores = cores.ok_or_else(|| format!("validator spec '{}' needs cores=N", s))?;
+        if count == 0 || cores == 0 {
+            return Err(format!("validator spec '{}': count and cores must be at least 1", s));
+        }
+        if !(block_time_secs.is_finite() && block_weight_secs.is_finite()) {
+            return Err(format!("validator spec '{}': block_time and block_weight must be finite", s));
+        }
+        if !(block_time_secs > 0.0 && block_weight_secs > 0.0 && block_weight_secs <= block_time_secs) {
+            return Err(format!(
+                "validator spec '{}': block_weight must be positive and at most block_time",
+                s
+            ));
+        }
+        Ok(Self { count, cores, block_time_secs, block_weight_secs })
+    }
+}
+
+/// Measured verify throughput a projection starts from
+#[derive(Clone, Debug)]
+pub struct VerifyRate {
+    pub scheme: String,
+    pub path: String,
+    pub tx_count: usize,
+    pub payload_bytes: usize,
+    /// Sequential verify TPS, used when there is no thread-sweep fit
+    pub sequential_tps: f64,
+    pub scalability: Option<Scalability>,
+}
+
+impl From<&SchemeRow> for VerifyRate {
+    fn from(row: &SchemeRow) -> Self {
+        Self {
+            scheme: row.scheme.clone(),
+            path: row.path.clone(),
+            tx_count: row.tx_count,
+            payload_bytes: row.payload_bytes,
+            sequential_tps: row.sequential_tps,
+            scalability: row.scalability.clone(),
+        }
+    }
+}
+
+/// How verify throughput grows with cores
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "lowercase")]
+pub enum ScalingModel {
+    Usl { contention: f64, coherency: f64 },
+    Amdahl { serial_fraction: f64 },
+    /// No fit available: every core adds a full core's throughput
+    Linear,
+}
+
+impl ScalingModel {
+    /// Throughput at `cores` relative to one core
+    pub fn speedup(&self, cores: f64) -> f64 {
+        match *self {
+            ScalingModel::Usl { contention, coherency } => {
+                cores / (1.0 + contention * (cores - 1.0) + coherency * cores * (cores - 1.0))
+            }
+            ScalingModel::Amdahl { serial_fraction } => cores / (1.0 + serial_fraction * (cores - 1.0)),
+            ScalingModel::Linear => cores,
+        }
+    }
+}
+
+/// Projected throughput of one validator class
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatorProjection {
+    pub spec: ValidatorSpec,
+    /// Signatures one validator of this class verifies per second
+    pub verify_tps: f64,
+    /// Transactions that fit in the block weight
+    pub tx_per_block: f64,
+    pub projected_tps: f64,
+}
+
+/// Projected network TPS from one scheme row's measurements
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CapacityProjection {
+    pub scheme: String,
+    pub path: String,
+    pub tx_count: usize,
+    pub payload_bytes: usize,
+    /// Measured one-thread verify rate
+    pub per_core_tps: f64,
+    pub model: ScalingModel,
+    /// Largest thread count the scaling model was fitted on
+    pub measured_threads: Option<usize>,
+    pub validators: Vec<ValidatorProjection>,
+    /// The slowest validator class's projected TPS
+    pub network_tps: f64,
+    pub assumptions: Vec<String>,
+}
+
+impl CapacityProjection {
+    /// Project a measured verify rate onto `validators`
+    pub fn project(row: &VerifyRate, validators: &[ValidatorSpec]) -> Self {
+        let fit = row.scalability.as_ref();
+        let per_core_tps = fit.map_or(row.sequential_tps, |f| f.single_thread_tps);
+        let measured_threads = fit.and_then(|f| f.threads.iter().copied().max());
+        let model = match fit {
+            Some(Scalability { usl: Some(usl), .. }) => {
+                ScalingModel::Usl { contention: usl.contention, coherency: usl.coherency }
+            }
+            Some(Scalability { amdahl: Some(amdahl), .. }) => {
+                ScalingModel::Amdahl { serial_fraction: amdahl.serial_fraction }
+            }
+            _ => ScalingModel::Linear,
+        };
+
+        let projections: Vec<ValidatorProjection> = validators
+            .iter()
+            .map(|spec| {
+                let verify_tps = per_core_tps * model.speedup(spec.cores as f64);
+                let tx_per_block = verify_tps * spec.block_weight_secs;
+                ValidatorProjection {
+                    spec: spec.clone(),
+                    verify_tps,
+                    tx_per_block,
+                    projected_tps: tx_per_block / spec.block_time_secs,
+                }
+            })
+            .collect();
+        let network_tps = projections.iter().map(|p| p.projected_tps).fold(f64::INFINITY, f64::min);
+
+        let mut assumptions = vec![format!(
+            "Per-core rate {:.1} TPS: one-thread {} verify of {} transactions of {} bytes ({} path)",
+            per_core_tps, row.scheme, row.tx_count, row.payload_bytes, row.path
+        )];
+        assumptions.push(match &model {
+            ScalingModel::Usl { contention, coherency } => {
+                format!("Cores scale by the USL fit (σ {:.4}, κ {:.6})", contention, coherency)
+            }
+            ScalingModel::Amdahl { serial_fraction } => {
+                format!("Cores scale by the Amdahl fit (serial fraction {:.1}%)", serial_fraction * 100.0)
+            }
+            ScalingModel::Linear => {
+                "Cores scale linearly (no --thread-sweep fit), so this is an upper bound".to_string()
+            }
+        });
+        let max_cores = validators.iter().map(|v| v.cores).max().unwrap_or(0);
+        if let Some(threads) = measured_threads.filter(|&t| max_cores > t) {
+            assumptions.push(format!(
+                "Extrapolated: {} cores projected from a fit over at most {} threads",
+                max_cores, threads
+            ));
+        }
+        assumptions.push(
+            "Every validator verifies every transaction: validators add no throughput, the slowest class bounds the network"
+                .to_string(),
+        );
+        assumptions.push(
+            "Signature verification is the only per-transaction cost: no execution, storage, gossip or consensus overhead"
+                .to_string(),
+        );
+        assumptions.push("Block weight seconds are spent verifying at the measured rate, with blocks always full".to_string());
+
+        Self {
+            scheme: row.scheme.clone(),
+            path: row.path.clone(),
+            tx_count: row.tx_count,
+            payload_bytes: row.payload_bytes,
+            per_core_tps,
+            model,
+            measured_threads,
+            validators: projections,
+            network_tps: if network_tps.is_finite() { network_tps } else { 0.0 },
+            assumptions,
+        }
+    }
+
+    /// The validator class that bounds the network
+    pub fn bottleneck(&self) -> Option<&ValidatorProjection> {
+        self.validators.iter().min_by(|a, b| a.projected_tps.total_cmp(&b.projected_tps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalability::ScalingPoint;
+
+    fn rate(scalability: Option<Scalability>) -> VerifyRate {
+        VerifyRate {
+            scheme: "shake-128f".to_string(),
+            path: "detached".to_string(),
+            tx_count: 100,
+            payload_bytes: 80,
+            sequential_tps: 100.0,
+            scalability,
+        }
+    }
+
+    fn spec(s: &str) -> ValidatorSpec {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_validator_spec() {
+        let parsed = spec("cores=16, count=3, block_time=12s, block_weight=4");
+        assert_eq!((parsed.count, parsed.cores), (3, 16));
+        assert_eq!((parsed.block_time_secs, parsed.block_weight_secs), (12.0, 4.0));
+        let defaults = spec("cores=8");
+        assert_eq!(defaults.count, 1);
+        assert_eq!(defaults.block_time_secs, DEFAULT_BLOCK_TIME_SECS);
+        assert_eq!(defaults.block_weight_secs, DEFAULT_BLOCK_WEIGHT_SECS);
+    }
+
+    #[test]
+    fn rejects_bad_validator_specs() {
+        for bad in [
+            "count=3",                              // no cores
+            "cores",                                // not KEY=VALUE
+            "cores=8,threads=4",                    // unknown key
+            "cores=many",                           // not a number
+            "cores=0",                              // no cores
+            "cores=8,count=0",                      // no validators
+            "cores=8,block_time=0",                 // zero block time
+            "cores=8,block_weight=-1",              // negative weight
+            "cores=8,block_time=2,block_weight=3",  // weight over block time
+            "cores=8,block_time=inf",               // infinite block time
+            "cores=8,block_time=inf,block_weight=inf",
+            "cores=8,block_weight=NaN",
+        ] {
+            assert!(bad.parse::<ValidatorSpec>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn projects_tps_from_an_amdahl_fit() {
+        // 5% serial: 16 cores give 16 / 1.75 ≈ 9.14× the 100 TPS of one core
+        let points: Vec<ScalingPoint> = [1, 2, 4, 8]
+            .into_iter()
+            .map(|threads| {
+                let n = threads as f64;
+                ScalingPoint { threads, tps: 100.0 * n / (1.0 + 0.05 * (n - 1.0)) }
+            })
+            .collect();
+        let fit = Scalability { usl: None, ..Scalability::fit(&points).unwrap() };
+        let projection = CapacityProjection::project(&rate(Some(fit)), &[spec("cores=16,block_time=6,block_weight=2")]);
+        assert!(matches!(projection.model, ScalingModel::Amdahl { .. }));
+        assert_eq!(projection.measured_threads, Some(8));
+        let verify_tps = 100.0 * 16.0 / 1.75;
+        assert!((projection.validators[0].verify_tps - verify_tps).abs() < 1e-6);
+        assert!((projection.network_tps - verify_tps * 2.0 / 6.0).abs() < 1e-6);
+        assert!(projection.assumptions.iter().any(|a| a.starts_with("Extrapolated: 16 cores")));
+    }
+
+    #[test]
+    fn projects_linear_scaling_without_a_fit() {
+        let projection = CapacityProjection::project(&rate(None), &[spec("cores=4,block_time=6,block_weight=3")]);
+        assert!(matches!(projection.model, ScalingModel::Linear));
+        // 100 TPS × 4 cores × 3 s of every 6 s block
+        assert!((projection.network_tps - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn slowest_class_bounds_the_network() {
+        let projection = CapacityProjection::project(
+            &rate(None),
+            &[
+                spec("cores=32,count=10"),
+                spec("cores=4,count=2,block_time=6,block_weight=1"),
+                spec("cores=8,count=5"),
+            ],
+        );
+        let bottleneck = projection.bottleneck().unwrap();
+        assert_eq!(bottleneck.spec.cores, 4);
+        assert_eq!(projection.network_tps, bottleneck.projected_tps);
+        // 100 TPS × 4 cores × 1 s of every 6 s block
+        assert!((projection.network_tps - 400.0 / 6.0).abs() < 1e-9);
+        assert!(projection.validators.iter().all(|v| v.projected_tps >= projection.network_tps));
+    }
+
+    #[test]
+    fn no_validators_project_nothing() {
+        let projection = CapacityProjection::project(&rate(None), &[]);
+        assert_eq!(projection.network_tps, 0.0);
+        assert!(projection.bottleneck().is_none());
+    }
+}
@@ -0,0 +1,119 @@
+//! Host and build fingerprint
+//!
+//! Verification throughput is a property of the machine as much as of the
+//! scheme, so every report records what it ran on: CPU model and feature
+//! flags, core counts, memory, kernel, and how the binary itself was built.
+//! Linux details come from `/proc`; elsewhere those fields are left empty.
+
+use serde::{Deserialize, Serialize};
+
+/// CPU flags that matter for hash-based and lattice signature performance
+const NOTABLE_CPU_FLAGS: &[&str] = &["sse4_1", "avx", "avx2", "avx512f", "bmi2", "aes", "sha_ni", "asimd", "sha2", "sha3"];
+
+/// The machine and binary a run happened on
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Environment {
+    pub cpu_model: Option<String>,
+    /// Every flag reported for the first CPU in /proc/cpuinfo
+    pub cpu_flags: Vec<String>,
+    pub physical_cores: usize,
+    pub logical_cores: usize,
+    pub memory_bytes: Option<u64>,
+    pub os: String,
+    pub arch: String,
+    pub kernel: Option<String>,
+    pub build: BuildInfo,
+}
+
+/// How the benchmark binary was compiled
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BuildInfo {
+    pub crate_version: String,
+    /// Short commit hash, suffixed `-dirty` for uncommitted changes; empty outside a git checkout
+    pub git_commit: String,
+    pub rustc_version: String,
+    /// Cargo profile, e.g. "release" or "debug"
+    pub profile: String,
+    pub features: Vec<String>,
+}
+
+impl Environment {
+    pub fn detect() -> Self {
+        let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+        let meminfo = std::fs::read_to_string("/proc/meminfo").unwrap_or_default();
+        Self {
+            cpu_model: cpu_model(&cpuinfo),
+            cpu_flags: cpu_flags(&cpuinfo),
+            physical_cores: num_cpus::get_physical(),
+            logical_cores: num_cpus::get(),
+            memory_bytes: memory_bytes(&meminfo),
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            kernel: std::fs::read_to_string("/proc/sys/kernel/osrelease")
+                .ok()
+                .map(|release| release.trim().to_string()),
+            build: BuildInfo::current(),
+        }
+    }
+
+    /// The subset of `cpu_flags` worth showing in a one-line summary
+    pub fn notable_cpu_flags(&self) -> Vec<&str> {
+        NOTABLE_CPU_FLAGS
+            .iter()
+            .copied()
+            .filter(|flag| self.cpu_flags.iter().any(|f| f == flag))
+            .collect()
+    }
+
+    pub fn memory_gib(&self) -> Option<f64> {
+        self.memory_bytes.map(|bytes| bytes as f64 / (1u64 << 30) as f64)
+    }
+}
+
+impl BuildInfo {
+    /// Build metadata baked in by `build.rs`
+    pub fn current() -> Self {
+        let features = [
+            ("mldsa", cfg!(feature = "mldsa")),
+            ("falcon", cfg!(feature = "falcon")),
+            ("ed25519", cfg!(feature = "ed25519")),
+        ];
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            git_commit: env!("QH_GIT_COMMIT").to_string(),
+            rustc_version: env!("QH_RUSTC_VERSION").to_string(),
+            profile: env!("QH_BUILD_PROFILE").to_string(),
+            features: features
+                .iter()
+                .filter(|(_, enabled)| *enabled)
+                .map(|(name, _)| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// First `key : value` in a /proc file whose key is one of `keys`
+fn proc_field<'a>(contents: &'a str, keys: &[&str]) -> Option<&'a str> {
+    contents.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        keys.contains(&key.trim()).then(|| value.trim())
+    })
+}
+
+fn cpu_model(cpuinfo: &str) -> Option<String> {
+    // x86 reports "model name"; ARM kernels vary between these
+    proc_field(cpuinfo, &["model name", "Model", "Hardware", "cpu model"]).map(str::to_string)
+}
+
+fn cpu_flags(cpuinfo: &str) -> Vec<String> {
+    proc_field(cpuinfo, &["flags", "Features"])
+        .map(|flags| flags.split_whitespace().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn memory_bytes(meminfo: &str) -> Option<u64> {
+    let kib = proc_field(meminfo, &["MemTotal"])?.trim_end_matches("kB").trim().parse::<u64>().ok()?;
+    Some(kib * 1024)
+}
@@ -0,0 +1,391 @@
+//! Extrinsic classification and encoding
+//!
+//! Just enough SCALE to count what a `chain_getBlock` response contains. Each
+//! extrinsic is hex of a compact-length-prefixed byte string whose first
+//! byte is the format version; its top two bits give the kind:
+//!
+//! - `10`: signed (extrinsic format v4), a user transaction
+//! - `01`: general (v5), a transaction authorized by its transaction extensions
+//! - `00`: bare, which covers inherents (timestamp, Aura slot) and unsigned
+//!   transactions; telling those two apart needs the runtime metadata
+//!
+//! Signed and general extrinsics are counted as transactions, bare ones as
+//! inherents.
+//!
+//! For `--real-tps --direct`, [`ExtrinsicFormat`] builds signed v4
+//! `transfer_keep_alive` extrinsics without the metadata. What a runtime
+//! needs that the RPC cannot tell us (pallet and call index, the
+//! `MultiSignature` variant of the scheme, whether `CheckMetadataHash` is in
+//! the extension list) comes from `--extrinsic-format`. The remaining
+//! extensions are those of the node templates: immortal era, nonce and tip
+//! as extra data, spec and transaction version, genesis hash and era block
+//! hash as implicit data.
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Blake2b512, Digest};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// The kind of one encoded extrinsic
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtrinsicKind {
+    Signed,
+    General,
+    Bare,
+}
+
+impl ExtrinsicKind {
+    /// Classify a `0x`-prefixed hex extrinsic as returned by `chain_getBlock`
+    pub fn classify(hex_extrinsic: &str) -> Result<Self, String> {
+        let bytes = hex::decode(hex_extrinsic.trim_start_matches("0x")).map_err(|e| format!("invalid extrinsic hex: {}", e))?;
+        let (length, prefix) = decode_compact(&bytes).ok_or("truncated extrinsic length")?;
+        let version = *bytes.get(prefix).ok_or("empty extrinsic")?;
+        if bytes.len() - prefix != length as usize {
+            return Err(format!("extrinsic length {} does not match its {} bytes", length, bytes.len() - prefix));
+        }
+        Ok(match version >> 6 {
+            0b10 => ExtrinsicKind::Signed,
+            0b01 => ExtrinsicKind::General,
+            0b00 => ExtrinsicKind::Bare,
+            _ => return Err(format!("unknown extrinsic version byte {:#04x}", version)),
+        })
+    }
+
+    /// Whether this is a user transaction rather than an inherent
+    pub fn is_transaction(self) -> bool {
+        self != ExtrinsicKind::Bare
+    }
+}
+
+/// Decode a SCALE compact integer, returning it and the bytes it used
+pub fn decode_compact(bytes: &[u8]) -> Option<(u64, usize)> {
+    let first = *bytes.first()?;
+    match first & 0b11 {
+        0b00 => Some((u64::from(first >> 2), 1)),
+        0b01 => {
+            let raw = u16::from_le_bytes(bytes.get(..2)?.try_into().ok()?);
+            Some((u64::from(raw >> 2), 2))
+        }
+        0b10 => {
+            let raw = u32::from_le_bytes(bytes.get(..4)?.try_into().ok()?);
+            Some((u64::from(raw >> 2), 4))
+        }
+        _ => {
+            // Big-integer mode: the upper six bits give the byte count minus four
+            let len = usize::from(first >> 2) + 4;
+            if len > 8 {
+                return None;
+            }
+            let mut raw = [0u8; 8];
+            raw[..len].copy_from_slice(bytes.get(1..1 + len)?);
+            Some((u64::from_le_bytes(raw), 1 + len))
+        }
+    }
+}
+
+/// Append `value` as a SCALE compact integer
+pub fn encode_compact(value: u64, out: &mut Vec<u8>) {
+    match value {
+        0..=0x3f => out.push((value as u8) << 2),
+        0x40..=0x3fff => out.extend_from_slice(&(((value as u16) << 2) | 0b01).to_le_bytes()),
+        0x4000..=0x3fff_ffff => out.extend_from_slice(&(((value as u32) << 2) | 0b10).to_le_bytes()),
+        _ => {
+            let len = 8 - value.leading_zeros() as usize / 8;
+            out.push((((len - 4) as u8) << 2) | 0b11);
+            out.extend_from_slice(&value.to_le_bytes()[..len]);
+        }
+    }
+}
+
+/// BLAKE2b-256, the hash Substrate uses for long signing payloads and extrinsic hashes
+pub fn blake2_256(data: &[u8]) -> [u8; 32] {
+    Blake2b::<U32>::digest(data).into()
+}
+
+/// The account id for a public key: 32-byte keys are used as is, longer ones hashed with BLAKE2b-256
+pub fn account_id(public_key: &[u8]) -> [u8; 32] {
+    match public_key.try_into() {
+        Ok(id) => id,
+        Err(_) => blake2_256(public_key),
+    }
+}
+
+/// SS58 address of `account` with the generic Substrate prefix (42)
+pub fn ss58(account: &[u8; 32]) -> String {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let mut bytes = vec![42u8];
+    bytes.extend_from_slice(account);
+    let checksum = Blake2b512::new().chain_update(b"SS58PRE").chain_update(&bytes).finalize();
+    bytes.extend_from_slice(&checksum[..2]);
+
+    // Base58: repeated division of the big-endian number, least significant digit first
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in &bytes {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    std::iter::repeat_n(b'1', zeros)
+        .chain(digits.iter().rev().map(|&d| ALPHABET[usize::from(d)]))
+        .map(char::from)
+        .collect()
+}
+
+/// Chain values every signing payload commits to
+#[derive(Clone, Debug)]
+pub struct ChainInfo {
+    pub genesis_hash: [u8; 32],
+    pub spec_version: u32,
+    pub transaction_version: u32,
+}
+
+/// How the target runtime lays out a signed transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtrinsicFormat {
+    /// Pallet and call index of `Balances::transfer_keep_alive`
+    pub transfer_call: (u8, u8),
+    /// `MultiSignature` variant that carries the scheme's signatures
+    pub signature_variant: u8,
+    /// Whether the runtime has the `CheckMetadataHash` extension
+    pub metadata_hash: bool,
+}
+
+impl Default for ExtrinsicFormat {
+    /// The solochain template layout, with the post-quantum signature after Ed25519, Sr25519 and ECDSA
+    fn default() -> Self {
+        Self { transfer_call: (4, 3), signature_variant: 3, metadata_hash: true }
+    }
+}
+
+impl FromStr for ExtrinsicFormat {
+    type Err = String;
+
+    /// Parse `call=4:3[,signature=3][,metadata_hash=true]`; missing keys keep their defaults
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut format = Self::default();
+        for field in s.split(',') {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| format!("expected KEY=VALUE in extrinsic format '{}', got '{}'", s, field))?;
+            let value = value.trim();
+            let invalid = |e: &dyn std::fmt::Display| format!("invalid {} '{}': {}", key.trim(), value, e);
+            match key.trim() {
+                "call" => {
+                    let (pallet, call) =
+                        value.split_once(':').ok_or_else(|| invalid(&"expected PALLET:CALL"))?;
+                    format.transfer_call = (
+                        pallet.trim().parse().map_err(|e| invalid(&e))?,
+                        call.trim().parse().map_err(|e| invalid(&e))?,
+                    );
+                }
+                "signature" => format.signature_variant = value.parse().map_err(|e| invalid(&e))?,
+                "metadata_hash" => format.metadata_hash = value.parse().map_err(|e| invalid(&e))?,
+                other => {
+                    return Err(format!(
+                        "unknown extrinsic format key '{}' (expected call, signature, metadata_hash)",
+                        other
+                    ))
+                }
+            }
+        }
+        Ok(format)
+    }
+}
+
+impl ExtrinsicFormat {
+    /// Encoded `transfer_keep_alive(dest, amount)`
+    pub fn transfer(&self, dest: &[u8; 32], amount: u64) -> Vec<u8> {
+        let mut call = vec![self.transfer_call.0, self.transfer_call.1];
+        call.push(0x00); // MultiAddress::Id
+        call.extend_from_slice(dest);
+        encode_compact(amount, &mut call);
+        call
+    }
+
+    /// Extension data carried in the extrinsic: era, nonce, tip and metadata hash mode
+    fn extra(&self, nonce: u64) -> Vec<u8> {
+        let mut extra = vec![0x00]; // Immortal era
+        encode_compact(nonce, &mut extra);
+        encode_compact(0, &mut extra); // Tip
+        if self.metadata_hash {
+            extra.push(0x00); // Mode::Disabled
+        }
+        extra
+    }
+
+    /// The bytes the signer signs: call, extra and implicit data, hashed when over 256 bytes
+    pub fn signing_payload(&self, chain: &ChainInfo, call: &[u8], nonce: u64) -> Vec<u8> {
+        let mut payload = call.to_vec();
+        payload.extend(self.extra(nonce));
+        payload.extend_from_slice(&chain.spec_version.to_le_bytes());
+        payload.extend_from_slice(&chain.transaction_version.to_le_bytes());
+        payload.extend_from_slice(&chain.genesis_hash);
+        payload.extend_from_slice(&chain.genesis_hash); // Block hash of the immortal era
+        if self.metadata_hash {
+            payload.push(0x00); // No metadata hash
+        }
+        if payload.len() > 256 {
+            blake2_256(&payload).to_vec()
+        } else {
+            payload
+        }
+    }
+
+    /// A length-prefixed signed v4 extrinsic
+    pub fn signed(&self, signer: &[u8; 32], signature: &[u8], call: &[u8], nonce: u64) -> Vec<u8> {
+        let mut body = vec![0x84, 0x00]; // Signed v4, MultiAddress::Id
+        body.extend_from_slice(signer);
+        body.push(self.signature_variant);
+        body.extend_from_slice(signature);
+        body.extend(self.extra(nonce));
+        body.extend_from_slice(call);
+
+        let mut extrinsic = Vec::with_capacity(body.len() + 5);
+        encode_compact(body.len() as u64, &mut extrinsic);
+        extrinsic.extend(body);
+        extrinsic
+    }
+}
+
+/// Extrinsic counts for one block
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockExtrinsics {
+    pub number: u64,
+    pub hash: String,
+    /// Signed and general extrinsics
+    pub transactions: usize,
+    /// Bare extrinsics: inherents and unsigned transactions
+    pub inherents: usize,
+    /// Encoded size of all extrinsics
+    pub bytes: usize,
+}
+
+impl BlockExtrinsics {
+    /// Count the hex extrinsics of block `number`
+    pub fn count(number: u64, hash: String, extrinsics: &[String]) -> Result<Self, String> {
+        let mut block = Self { number, hash, transactions: 0, inherents: 0, bytes: 0 };
+        for extrinsic in extrinsics {
+            match ExtrinsicKind::classify(extrinsic)? {
+                kind if kind.is_transaction() => block.transactions += 1,
+                _ => block.inherents += 1,
+            }
+            block.bytes += extrinsic.trim_start_matches("0x").len() / 2;
+        }
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Alice's well-known sr25519 public key
+    const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+    fn alice() -> [u8; 32] {
+        hex::decode(ALICE).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn compact_boundaries() {
+        for (value, encoded) in [
+            (0, "00"),
+            (0x3f, "fc"),
+            (0x40, "0101"),
+            (0x3fff, "fdff"),
+            (0x4000, "02000100"),
+            (0x3fff_ffff, "feffffff"),
+            (0x4000_0000, "0300000040"),
+            (1 << 32, "070000000001"),
+            (u64::MAX, "13ffffffffffffffff"),
+        ] {
+            let mut out = Vec::new();
+            encode_compact(value, &mut out);
+            assert_eq!(hex::encode(&out), encoded, "encoding {:#x}", value);
+            assert_eq!(decode_compact(&out), Some((value, out.len())), "decoding {:#x}", value);
+        }
+    }
+
+    #[test]
+    fn compact_rejects_truncated_input() {
+        assert_eq!(decode_compact(&[]), None);
+        assert_eq!(decode_compact(&[0x01]), None);
+        assert_eq!(decode_compact(&[0x02, 0x00, 0x01]), None);
+        assert_eq!(decode_compact(&[0x03, 0x00, 0x00, 0x00]), None);
+    }
+
+    #[test]
+    fn ss58_of_alice() {
+        assert_eq!(ss58(&alice()), "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY");
+    }
+
+    #[test]
+    fn account_id_hashes_long_keys() {
+        assert_eq!(account_id(&alice()), alice());
+        let long_key = [7u8; 48];
+        assert_eq!(account_id(&long_key), blake2_256(&long_key));
+    }
+
+    #[test]
+    fn signed_v4_transfer() {
+        let format = ExtrinsicFormat::default();
+        let call = format.transfer(&alice(), 1);
+        let extrinsic = format.signed(&alice(), &[0xaa; 64], &call, 7);
+
+        let expected = [
+            "2d02",                // Compact length 139
+            "84",                  // Signed, v4
+            "00",                  // MultiAddress::Id
+            ALICE,                 // Signer
+            "03",                  // MultiSignature variant
+            &"aa".repeat(64),      // Signature
+            "00",                  // Immortal era
+            "1c",                  // Nonce 7
+            "00",                  // Tip 0
+            "00",                  // CheckMetadataHash disabled
+            "0403",                // Balances::transfer_keep_alive
+            "00",                  // MultiAddress::Id
+            ALICE,                 // Destination
+            "04",                  // Amount 1
+        ]
+        .concat();
+        assert_eq!(hex::encode(&extrinsic), expected);
+        assert_eq!(
+            ExtrinsicKind::classify(&format!("0x{}", expected)),
+            Ok(ExtrinsicKind::Signed)
+        );
+    }
+
+    #[test]
+    fn signing_payload_is_hashed_over_256_bytes() {
+        let chain = ChainInfo { genesis_hash: [0x11; 32], spec_version: 104, transaction_version: 2 };
+        let format = ExtrinsicFormat::default();
+        let call = format.transfer(&alice(), 1);
+        let payload = format.signing_payload(&chain, &call, 7);
+        let expected = [
+            &hex::encode(&call),
+            "00",                  // Immortal era
+            "1c",                  // Nonce 7
+            "00",                  // Tip 0
+            "00",                  // CheckMetadataHash disabled
+            "68000000",            // Spec version 104
+            "02000000",            // Transaction version 2
+            &"11".repeat(32),      // Genesis hash
+            &"11".repeat(32),      // Era block hash
+            "00",                  // No metadata hash
+        ]
+        .concat();
+        assert_eq!(hex::encode(&payload), expected);
+
+        let long_call = vec![0u8; 300];
+        let payload = format.signing_payload(&chain, &long_call, 7);
+        assert_eq!(payload.len(), 32);
+    }
+}
@@ -0,0 +1,276 @@
+//! Block head timeline
+//!
+//! Sampling the best block twice only gives an average block time. Instead,
+//! `--network` subscribes to `chain_subscribeNewHeads` and
+//! `chain_subscribeFinalizedHeads` over WebSocket and timestamps every header
+//! as it arrives, which shows:
+//!
+//! - the block-time distribution: gaps between successive new best blocks
+//! - stalls: the longest gap, and gaps over twice the median block time
+//! - reorgs: a new head at or below the best number already seen, meaning
+//!   the node switched forks
+//! - finality lag: time from a block's first appearance as a new head to its
+//!   finalization, and how many blocks finality trails the best head by
+//!
+//! A finalized-head notification finalizes every block up to its number, so
+//! blocks finalized in one step share the same finalization time.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::latency::LatencyHistogram;
+use crate::report::LatencyRow;
+use crate::ws::WsClient;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HeadKind {
+    New,
+    Finalized,
+}
+
+/// One header notification
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HeadEvent {
+    /// Seconds since the subscriptions started
+    pub at_secs: f64,
+    pub kind: HeadKind,
+    pub number: u64,
+    pub parent_hash: String,
+}
+
+/// Every header seen during one watch
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HeadTimeline {
+    pub duration_secs: f64,
+    pub events: Vec<HeadEvent>,
+}
+
+/// What the timeline shows
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HeadStats {
+    pub new_heads: usize,
+    pub finalized_heads: usize,
+    /// New heads at or below the best number already seen
+    pub reorgs: usize,
+    /// Gaps between successive new best blocks
+    pub block_time: LatencyRow,
+    pub longest_gap_secs: f64,
+    /// Best block before the longest gap
+    pub longest_gap_after: u64,
+    /// Block that ended the longest gap, `None` if none arrived before the watch ended
+    pub longest_gap_until: Option<u64>,
+    /// Gaps longer than twice the median block time, including one still open at the end
+    pub stalls: usize,
+    /// New head to finalized, per block
+    pub finality_lag: LatencyRow,
+    /// Best minus finalized number, averaged over finalized-head notifications
+    pub finality_lag_blocks_mean: f64,
+    pub finality_lag_blocks_max: u64,
+}
+
+/// Subscribe to new and finalized heads on `url` and record them for `duration`
+pub async fn watch(url: &str, duration: Duration) -> Result<HeadTimeline, String> {
+    let mut client = WsClient::connect(url).await?;
+    let new_heads = client.subscribe("chain_subscribeNewHeads", vec![]).await?;
+    let finalized_heads = client.subscribe("chain_subscribeFinalizedHeads", vec![]).await?;
+    let start = Instant::now();
+    let deadline = tokio::time::Instant::from_std(start + duration);
+
+    let mut events = Vec::new();
+    while let Ok(notification) = tokio::time::timeout_at(deadline, client.next_notification()).await {
+        let notification = notification?;
+        let kind = if notification.subscription == new_heads {
+            HeadKind::New
+        } else if notification.subscription == finalized_heads {
+            HeadKind::Finalized
+        } else {
+            continue;
+        };
+        events.push(HeadEvent {
+            at_secs: notification.received.duration_since(start).as_secs_f64(),
+            kind,
+            number: header_number(&notification.result)?,
+            parent_hash: notification.result.get("parentHash").and_then(Value::as_str).unwrap_or_default().to_string(),
+        });
+    }
+    client.close().await;
+    Ok(HeadTimeline { duration_secs: start.elapsed().as_secs_f64(), events })
+}
+
+/// The `0x`-hex block number of a header
+pub fn header_number(header: &Value) -> Result<u64, String> {
+    let hex = header.get("number").and_then(Value::as_str).ok_or("header has no number")?;
+    u64::from_str_radix(hex.trim_start_matches("0x"), 16).map_err(|e| format!("invalid block number '{}': {}", hex, e))
+}
+
+impl HeadTimeline {
+    /// Summarize the timeline; `None` without any new heads
+    pub fn stats(&self) -> Option<HeadStats> {
+        let mut best: Option<(u64, f64)> = None;
+        let mut reorgs = 0;
+        let mut gaps: Vec<(f64, u64, Option<u64>)> = Vec::new();
+        // When each block number was first seen as a new head
+        let mut seen: HashMap<u64, f64> = HashMap::new();
+        let mut finalized: Option<u64> = None;
+        let mut lag = LatencyHistogram::new();
+        let mut lag_blocks: Vec<u64> = Vec::new();
+
+        for event in &self.events {
+            match event.kind {
+                HeadKind::New => {
+                    seen.entry(event.number).or_insert(event.at_secs);
+                    match best {
+                        Some((number, _)) if event.number <= number => reorgs += 1,
+                        Some((number, at)) => {
+                            gaps.push((event.at_secs - at, number, Some(event.number)));
+                            best = Some((event.number, event.at_secs));
+                        }
+                        None => best = Some((event.number, event.at_secs)),
+                    }
+                }
+                HeadKind::Finalized => {
+                    // Everything since the last finalized head is final now
+                    let from = finalized.map_or(event.number, |f| f + 1);
+                    for number in from..=event.number {
+                        if let Some(&at) = seen.get(&number) {
+                            lag.record(((event.at_secs - at).max(0.0) * 1e9) as u64);
+                        }
+                    }
+                    finalized = Some(finalized.map_or(event.number, |f| f.max(event.number)));
+                    if let Some((number, _)) = best {
+                        lag_blocks.push(number.saturating_sub(event.number));
+                    }
+                }
+            }
+        }
+        let (best_number, best_at) = best?;
+        // A chain that stopped producing shows as one open gap at the end
+        let trailing = (self.duration_secs - best_at, best_number, None);
+
+        let mut block_time = LatencyHistogram::new();
+        for &(gap, ..) in &gaps {
+            block_time.record((gap * 1e9) as u64);
+        }
+        let median = block_time.percentile(50.0).as_secs_f64();
+        let stalls = gaps
+            .iter()
+            .chain(std::iter::once(&trailing))
+            .filter(|g| median > 0.0 && g.0 > 2.0 * median)
+            .count();
+        let longest = gaps.iter().copied().fold(trailing, |a, g| if g.0 >= a.0 { g } else { a });
+
+        Some(HeadStats {
+            new_heads: self.events.iter().filter(|e| e.kind == HeadKind::New).count(),
+            finalized_heads: self.events.iter().filter(|e| e.kind == HeadKind::Finalized).count(),
+            reorgs,
+            block_time: (&block_time).into(),
+            longest_gap_secs: longest.0,
+            longest_gap_after: longest.1,
+            longest_gap_until: longest.2,
+            stalls,
+            finality_lag: (&lag).into(),
+            finality_lag_blocks_mean: if lag_blocks.is_empty() {
+                0.0
+            } else {
+                lag_blocks.iter().sum::<u64>() as f64 / lag_blocks.len() as f64
+            },
+            finality_lag_blocks_max: lag_blocks.iter().copied().max().unwrap_or(0),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use HeadKind::{Finalized, New};
+
+    fn event(kind: HeadKind, at_secs: f64, number: u64) -> HeadEvent {
+        HeadEvent { at_secs, kind, number, parent_hash: String::new() }
+    }
+
+    fn timeline(duration_secs: f64, events: &[(HeadKind, f64, u64)]) -> HeadTimeline {
+        HeadTimeline {
+            duration_secs,
+            events: events.iter().map(|&(kind, at, number)| event(kind, at, number)).collect(),
+        }
+    }
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() <= expected * 0.001
+    }
+
+    #[test]
+    fn counts_heads_at_or_below_the_best_as_reorgs() {
+        let stats = timeline(13.0, &[(New, 0.0, 1), (New, 6.0, 2), (New, 7.0, 2), (New, 8.0, 1), (New, 12.0, 3)])
+            .stats()
+            .unwrap();
+        assert_eq!((stats.new_heads, stats.reorgs), (5, 2));
+        // Reorged heads are not block times: 1 → 2 and 2 → 3
+        assert_eq!(stats.block_time.count, 2);
+        assert!(close(stats.block_time.p50_ms, 6000.0), "{}", stats.block_time.p50_ms);
+        assert_eq!(stats.stalls, 0);
+    }
+
+    #[test]
+    fn counts_gaps_over_twice_the_median_as_stalls() {
+        let stats = timeline(
+            48.0,
+            &[(New, 0.0, 1), (New, 6.0, 2), (New, 12.0, 3), (New, 25.0, 4), (New, 31.0, 5), (New, 42.5, 6)],
+        )
+        .stats()
+        .unwrap();
+        // Median 6 s: the 13 s gap is a stall, the 11.5 s gap is not
+        assert_eq!(stats.stalls, 1);
+        assert!(close(stats.longest_gap_secs, 13.0));
+        assert_eq!((stats.longest_gap_after, stats.longest_gap_until), (3, Some(4)));
+    }
+
+    #[test]
+    fn trailing_open_gap_counts_when_the_chain_stops() {
+        let stats = timeline(60.0, &[(New, 0.0, 1), (New, 6.0, 2), (New, 12.0, 3), (New, 18.0, 4)])
+            .stats()
+            .unwrap();
+        assert_eq!(stats.stalls, 1);
+        assert!(close(stats.longest_gap_secs, 42.0));
+        assert_eq!((stats.longest_gap_after, stats.longest_gap_until), (4, None));
+        // The open gap is not a block time
+        assert_eq!(stats.block_time.count, 3);
+    }
+
+    #[test]
+    fn one_finalized_head_finalizes_every_block_up_to_it() {
+        let stats = timeline(
+            20.0,
+            &[
+                (New, 0.0, 1),
+                // The subscription starts with the current finalized head
+                (Finalized, 0.1, 0),
+                (New, 6.0, 2),
+                (New, 12.0, 3),
+                (Finalized, 15.0, 3),
+                (New, 18.0, 4),
+                // Older than what is already final: finalizes nothing new
+                (Finalized, 19.0, 2),
+            ],
+        )
+        .stats()
+        .unwrap();
+        assert_eq!(stats.finalized_heads, 3);
+        // Blocks 1, 2 and 3 waited 15, 9 and 3 s for the same notification
+        assert_eq!(stats.finality_lag.count, 3);
+        assert!(close(stats.finality_lag.p50_ms, 9000.0), "{}", stats.finality_lag.p50_ms);
+        assert!(close(stats.finality_lag.max_ms, 15000.0), "{}", stats.finality_lag.max_ms);
+        // Best minus finalized: 1 - 0, 3 - 3, then 4 - 2
+        assert_eq!(stats.finality_lag_blocks_max, 2);
+        assert!(close(stats.finality_lag_blocks_mean, 1.0));
+    }
+
+    #[test]
+    fn no_new_heads_no_stats() {
+        assert!(timeline(10.0, &[]).stats().is_none());
+        assert!(timeline(10.0, &[(Finalized, 1.0, 5)]).stats().is_none());
+    }
+}
@@ -0,0 +1,740 @@
+//! Self-contained HTML report
+//!
+//! One file, no scripts, fonts or external assets, so it opens on an
+//! air-gapped machine. Charts are inline SVG drawn from the report data:
+//! TPS and speedup across the segment sweep (against the ideal of one core
+//! per segment), thread scaling against the Amdahl and USL fits, the verify
+//! latency histogram, per-segment torus load, and offered against included
+//! load over a `--load` run.
+
+use std::fmt::Write;
+
+use crate::load::LoadWindow;
+use crate::report::{BenchmarkReport, StrategyRow};
+use crate::simulate::{self, Consensus};
+
+const WIDTH: f64 = 720.0;
+const HEIGHT: f64 = 320.0;
+const MARGIN_LEFT: f64 = 70.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 36.0;
+const MARGIN_BOTTOM: f64 = 48.0;
+const PALETTE: [&str; 6] = ["#2563eb", "#dc2626", "#16a34a", "#9333ea", "#ea580c", "#0891b2"];
+
+const STYLE: &str = "body{font-family:system-ui,sans-serif;max-width:1500px;margin:2em auto;padding:0 1em;color:#1f2937}\
+h1{font-size:1.6em}h2{border-bottom:1px solid #e5e7eb;padding-bottom:.2em;margin-top:2em}\
+table{border-collapse:collapse;margin:1em 0;font-size:.9em}td,th{border:1px solid #e5e7eb;padding:.3em .6em}\
+th{background:#f9fafb;text-align:left}td.num{text-align:right;font-variant-numeric:tabular-nums}\
+.charts{display:flex;flex-wrap:wrap;gap:1em}.charts svg{border:1px solid #e5e7eb;background:#fff}\
+.pass{color:#16a34a}.fail{color:#dc2626}.muted{color:#6b7280}";
+
+/// A named polyline on a chart
+struct Series {
+    name: String,
+    points: Vec<(f64, f64)>,
+    color: &'static str,
+    dashed: bool,
+}
+
+/// Render `report` as a standalone HTML document
+pub fn render(report: &BenchmarkReport) -> String {
+    let mut out = String::new();
+    let _ = write_page(&mut out, report);
+    out
+}
+
+fn write_page(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "<!DOCTYPE html>")?;
+    writeln!(out, "<html lang=\"en\"><head><meta charset=\"utf-8\">")?;
+    writeln!(out, "<title>QuantumHarmony Benchmark Report</title>")?;
+    writeln!(out, "<style>{}</style></head><body>", STYLE)?;
+    writeln!(out, "<h1>QuantumHarmony Benchmark Report</h1>")?;
+    writeln!(
+        out,
+        "<p class=\"muted\">sphincs-benchmark v{}, {} mode, schema v{}, generated at Unix time {}</p>",
+        escape(&report.tool_version),
+        escape(&report.config.mode),
+        report.schema_version,
+        report.generated_at
+    )?;
+
+    write_environment(out, report)?;
+    write_assertions(out, report)?;
+    for group in groups(&report.results) {
+        write_group(out, report, &group)?;
+    }
+    write_capacity(out, report)?;
+    write_network(out, report)?;
+
+    writeln!(out, "</body></html>")
+}
+
+fn write_environment(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    let env = &report.environment;
+    let build = &env.build;
+    writeln!(out, "<h2>Environment</h2><table>")?;
+    let rows = [
+        ("CPU", env.cpu_model.clone().unwrap_or_else(|| "unknown".to_string())),
+        ("Cores", format!("{} physical / {} logical", env.physical_cores, env.logical_cores)),
+        ("CPU flags", env.notable_cpu_flags().join(" ")),
+        ("Memory", env.memory_gib().map_or("unknown".to_string(), |gib| format!("{:.1} GiB", gib))),
+        ("OS", format!("{} {} {}", env.os, env.arch, env.kernel.as_deref().unwrap_or(""))),
+        ("Build", format!("v{} {} ({})", build.crate_version, build.git_commit, build.profile)),
+        ("Toolchain", build.rustc_version.clone()),
+        ("Features", build.features.join(", ")),
+        ("Schemes", report.config.schemes.join(", ")),
+        (
+            "Torus sweep",
+            report.config.sweep_segments.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", "),
+        ),
+    ];
+    for (key, value) in rows {
+        writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", key, escape(&value))?;
+    }
+    writeln!(out, "</table>")
+}
+
+fn write_assertions(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    if report.assertions.is_empty() {
+        return Ok(());
+    }
+    writeln!(out, "<h2>Assertions</h2><table><tr><th>Result</th><th>Requirement</th><th>Worst observed</th></tr>")?;
+    for a in &report.assertions {
+        let (class, label) = if a.passed { ("pass", "PASS") } else { ("fail", "FAIL") };
+        let observed = match (a.actual, &a.worst_row) {
+            (Some(actual), Some(row)) => format!("{:.3} ({})", actual, row),
+            _ => "not measured".to_string(),
+        };
+        writeln!(
+            out,
+            "<tr><td class=\"{}\">{}</td><td>{}</td><td>{}</td></tr>",
+            class,
+            label,
+            escape(&a.requirement),
+            escape(&observed)
+        )?;
+    }
+    writeln!(out, "</table>")
+}
+
+/// Rows measured on the same transaction set
+struct Group<'a> {
+    title: String,
+    rows: Vec<&'a StrategyRow>,
+}
+
+fn groups(results: &[StrategyRow]) -> Vec<Group<'_>> {
+    let mut groups: Vec<Group> = Vec::new();
+    for row in results {
+        let title = row.group_label();
+        match groups.last_mut() {
+            Some(group) if group.title == title => group.rows.push(row),
+            _ => groups.push(Group { title, rows: vec![row] }),
+        }
+    }
+    groups
+}
+
+fn write_group(out: &mut String, report: &BenchmarkReport, group: &Group) -> std::fmt::Result {
+    writeln!(out, "<h2>{}</h2>", escape(&group.title))?;
+    writeln!(out, "<div class=\"charts\">")?;
+
+    let sweep: Vec<&StrategyRow> = group.rows.iter().copied().filter(|r| r.kind == "sweep").collect();
+    let baseline = group.rows.iter().find(|r| r.kind == "baseline");
+    if !sweep.is_empty() {
+        let point = |r: &&StrategyRow, y: f64| (r.segments.unwrap_or(1) as f64, y);
+        let mut tps = vec![Series {
+            name: "Torus sweep".to_string(),
+            points: sweep.iter().map(|r| point(r, r.tps.mean)).collect(),
+            color: PALETTE[0],
+            dashed: false,
+        }];
+        if let Some(baseline) = baseline {
+            tps.push(Series {
+                name: "Sequential".to_string(),
+                points: sweep.iter().map(|r| point(r, baseline.tps.mean)).collect(),
+                color: PALETTE[1],
+                dashed: true,
+            });
+        }
+        out.push_str(&line_chart("Throughput vs segments", "segments", "TPS", &tps, true));
+
+        // With one core per segment at best, the ideal speedup flattens at the core count
+        let cores = report.environment.logical_cores.max(1) as f64;
+        let speedup = [
+            Series {
+                name: "Measured".to_string(),
+                points: sweep.iter().map(|r| point(r, r.speedup)).collect(),
+                color: PALETTE[0],
+                dashed: false,
+            },
+            Series {
+                name: format!("Ideal ({} cores)", cores),
+                points: sweep.iter().map(|r| point(r, (r.segments.unwrap_or(1) as f64).min(cores))).collect(),
+                color: PALETTE[2],
+                dashed: true,
+            },
+        ];
+        out.push_str(&line_chart("Speedup vs ideal", "segments", "speedup (×)", &speedup, true));
+    }
+
+    // Measured thread scaling against the fitted models
+    let threads: Vec<&StrategyRow> = group.rows.iter().copied().filter(|r| r.kind == "threads").collect();
+    let scalability = group.rows.first().and_then(|first| {
+        report
+            .schemes
+            .iter()
+            .find(|s| {
+                s.suite == first.suite
+                    && s.scheme == first.scheme
+                    && s.path == first.path
+                    && s.tx_count == first.tx_count
+                    && s.payload_bytes == first.payload_bytes
+            })
+            .and_then(|s| s.scalability.as_ref())
+    });
+    if let Some(fit) = scalability.filter(|_| !threads.is_empty()) {
+        let xs: Vec<f64> = threads.iter().map(|r| r.threads.unwrap_or(1) as f64).collect();
+        let mut series = vec![Series {
+            name: "Measured".to_string(),
+            points: threads.iter().map(|r| (r.threads.unwrap_or(1) as f64, r.tps.mean / fit.single_thread_tps)).collect(),
+            color: PALETTE[0],
+            dashed: false,
+        }];
+        if let Some(amdahl) = &fit.amdahl {
+            series.push(Series {
+                name: format!("Amdahl (σ {:.3})", amdahl.serial_fraction),
+                points: xs.iter().map(|&n| (n, amdahl.speedup(n))).collect(),
+                color: PALETTE[3],
+                dashed: true,
+            });
+        }
+        if let Some(usl) = &fit.usl {
+            series.push(Series {
+                name: format!("USL (σ {:.3}, κ {:.5})", usl.contention, usl.coherency),
+                points: xs.iter().map(|&n| (n, usl.speedup(n))).collect(),
+                color: PALETTE[4],
+                dashed: true,
+            });
+        }
+        out.push_str(&line_chart("Speedup vs threads", "rayon threads", "speedup over 1 thread (×)", &series, true));
+    }
+
+    if let Some(baseline) = baseline.filter(|b| !b.latency.histogram.is_empty()) {
+        let bars: Vec<(String, f64)> = baseline
+            .latency
+            .histogram
+            .iter()
+            .map(|b| (format!("{:.2}", (b.lower_ms + b.upper_ms) / 2.0), b.count as f64))
+            .collect();
+        out.push_str(&bar_chart("Sequential verify latency", "latency (ms, bucket midpoint)", "signatures", &bars));
+    }
+
+    // Prefer the explicit --torus run, else the fastest sweep point
+    let mesh_row = group
+        .rows
+        .iter()
+        .find(|r| r.kind == "torus" && r.mesh.is_some())
+        .or_else(|| {
+            sweep
+                .iter()
+                .filter(|r| r.mesh.is_some())
+                .max_by(|a, b| a.tps.mean.total_cmp(&b.tps.mean))
+        });
+    if let Some(mesh) = mesh_row.and_then(|r| r.mesh.as_ref()) {
+        let [x, y, z] = mesh.dims;
+        let bars: Vec<(String, f64)> = mesh.load.iter().enumerate().map(|(i, &l)| (i.to_string(), l as f64)).collect();
+        let title = format!(
+            "Per-segment load, {}×{}×{} torus ({} steals, imbalance {:.2})",
+            x, y, z, mesh.total_steals, mesh.imbalance
+        );
+        out.push_str(&bar_chart(&title, "segment", "transactions", &bars));
+    }
+    writeln!(out, "</div>")?;
+
+    writeln!(
+        out,
+        "<table><tr><th>Strategy</th><th>Kind</th><th>Segments</th><th>Threads</th><th>TPS</th><th>±95%</th>\
+         <th>Speedup</th><th>p50 ms</th><th>p99 ms</th><th>Verified</th></tr>"
+    )?;
+    for row in &group.rows {
+        writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td><td class=\"num\">{:.0}</td><td class=\"num\">{:.0}</td>\
+             <td class=\"num\">{:.2}×</td><td class=\"num\">{:.2}</td><td class=\"num\">{:.2}</td><td class=\"num\">{}/{}</td></tr>",
+            escape(&row.strategy),
+            row.kind,
+            row.segments.map(|s| s.to_string()).unwrap_or_default(),
+            row.threads.map(|t| t.to_string()).unwrap_or_default(),
+            row.tps.mean,
+            row.tps.ci95,
+            row.speedup,
+            row.latency.p50_ms,
+            row.latency.p99_ms,
+            row.verified,
+            row.total
+        )?;
+    }
+    writeln!(out, "</table>")
+}
+
+fn write_capacity(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    if report.capacity.is_empty() {
+        return Ok(());
+    }
+    writeln!(
+        out,
+        "<h2>Capacity Projection</h2><table><tr><th>Scheme</th><th>Path</th><th>Tx</th><th>Per-core TPS</th>\
+         <th>Validators</th><th>Projected TPS</th></tr>"
+    )?;
+    for p in &report.capacity {
+        let validators: Vec<String> = p
+            .validators
+            .iter()
+            .map(|v| {
+                escape(&format!(
+                    "{} × {} cores ({}s blocks, {}s weight): {:.1} TPS",
+                    v.spec.count, v.spec.cores, v.spec.block_time_secs, v.spec.block_weight_secs, v.projected_tps
+                ))
+            })
+            .collect();
+        writeln!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{:.1}</td><td>{}</td><td class=\"num\">{:.1}</td></tr>",
+            escape(&p.scheme),
+            escape(&p.path),
+            p.tx_count,
+            p.per_core_tps,
+            validators.join("<br>"),
+            p.network_tps
+        )?;
+    }
+    writeln!(out, "</table><ul>")?;
+    let mut seen = Vec::new();
+    for assumption in report.capacity.iter().flat_map(|p| &p.assumptions) {
+        if !seen.contains(&assumption) {
+            writeln!(out, "<li>{}</li>", escape(assumption))?;
+            seen.push(assumption);
+        }
+    }
+    writeln!(out, "</ul>")
+}
+
+fn write_network(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    if let Some(network) = &report.network {
+        writeln!(out, "<h2>Network</h2><table>")?;
+        writeln!(out, "<tr><th>Blocks</th><td>#{} → #{} ({} produced)</td></tr>", network.start_block, network.end_block, network.blocks_produced)?;
+        writeln!(out, "<tr><th>Block time</th><td>{:.2} s</td></tr>", network.block_time_secs)?;
+        if let Some(tps) = network.measured_tps {
+            writeln!(
+                out,
+                "<tr><th>Measured TPS</th><td>{:.2} ({} transactions, {} inherents)</td></tr>",
+                tps, network.transactions, network.inherents
+            )?;
+        }
+        match &report.simulation {
+            Some(_) => writeln!(out, "<tr><th>Simulated TPS</th><td>{:.1}</td></tr>", network.estimated_tps)?,
+            None => writeln!(out, "<tr><th>Estimated TPS</th><td>{:.0} (assumed block fill, not measured)</td></tr>", network.estimated_tps)?,
+        }
+        if network.theoretical_max_tps > 0.0 {
+            writeln!(out, "<tr><th>Projected capacity</th><td>{:.0} TPS</td></tr>", network.theoretical_max_tps)?;
+        }
+        if let Some(heads) = &network.heads {
+            writeln!(
+                out,
+                "<tr><th>Heads</th><td>{} new, {} finalized, {} reorgs</td></tr>",
+                heads.new_heads, heads.finalized_heads, heads.reorgs
+            )?;
+            writeln!(
+                out,
+                "<tr><th>Longest gap</th><td>{:.2} s after #{}, {} stalls</td></tr>",
+                heads.longest_gap_secs, heads.longest_gap_after, heads.stalls
+            )?;
+            writeln!(
+                out,
+                "<tr><th>Finality lag</th><td>p50 {:.2} s, p95 {:.2} s, max {:.2} s</td></tr>",
+                heads.finality_lag.p50_ms / 1000.0,
+                heads.finality_lag.p95_ms / 1000.0,
+                heads.finality_lag.max_ms / 1000.0
+            )?;
+        }
+        writeln!(out, "</table>")?;
+        if let Some(heads) = network.heads.as_ref().filter(|h| !h.block_time.histogram.is_empty()) {
+            let bars: Vec<(String, f64)> = heads
+                .block_time
+                .histogram
+                .iter()
+                .map(|b| (format!("{:.2}", (b.lower_ms + b.upper_ms) / 2000.0), b.count as f64))
+                .collect();
+            writeln!(out, "<div class=\"charts\">")?;
+            out.push_str(&bar_chart("Block time distribution", "block time (s, bucket midpoint)", "blocks", &bars));
+            writeln!(out, "</div>")?;
+        }
+        // At most ~48 bars, so long runs stay readable
+        let step = (network.blocks.len() / 48).max(1);
+        let bars: Vec<(String, f64)> = network
+            .blocks
+            .iter()
+            .step_by(step)
+            .map(|b| (format!("#{}", b.number), b.transactions as f64))
+            .collect();
+        if !bars.is_empty() {
+            writeln!(out, "<div class=\"charts\">")?;
+            out.push_str(&bar_chart("Transactions per block", "block", "signed extrinsics", &bars));
+            writeln!(out, "</div>")?;
+        }
+    }
+    if let Some(sim) = &report.simulation {
+        writeln!(out, "<h2>Simulation</h2><table>")?;
+        writeln!(out, "<tr><th>Validators</th><td>{}</td></tr>", sim.validators)?;
+        match sim.consensus {
+            Consensus::Aura => writeln!(out, "<tr><th>Consensus</th><td>Aura, one seal per block</td></tr>")?,
+            Consensus::Hotstuff => writeln!(
+                out,
+                "<tr><th>Consensus</th><td>HotStuff, quorum {} of {}</td></tr>",
+                sim.quorum, sim.validators
+            )?,
+        }
+        writeln!(out, "<tr><th>Offered load</th><td>{:.1} TPS for {} s</td></tr>", sim.offered_tps, sim.duration_secs)?;
+        writeln!(
+            out,
+            "<tr><th>Transactions</th><td>{} submitted, {} included, {} dropped, {} pending</td></tr>",
+            sim.submitted, sim.included, sim.dropped, sim.pending
+        )?;
+        writeln!(out, "<tr><th>Blocks</th><td>{} ({} full, {} orphaned)</td></tr>", sim.blocks, sim.full_blocks, sim.orphaned_blocks)?;
+        writeln!(
+            out,
+            "<tr><th>Inclusion latency</th><td>p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms</td></tr>",
+            sim.inclusion_latency.p50_ms, sim.inclusion_latency.p95_ms, sim.inclusion_latency.p99_ms
+        )?;
+        writeln!(
+            out,
+            "<tr><th>Finality latency</th><td>p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms</td></tr>",
+            sim.finality_latency.p50_ms, sim.finality_latency.p95_ms, sim.finality_latency.p99_ms
+        )?;
+        writeln!(
+            out,
+            "<tr><th>Consensus load</th><td>{:.0} signatures and {:.0} verifications per block</td></tr>",
+            sim.signatures_per_block(),
+            sim.verifications_per_block()
+        )?;
+        let (min, mean, max) = sim.utilization();
+        writeln!(
+            out,
+            "<tr><th>Validator load</th><td>{:.1}% min, {:.1}% mean, {:.1}% max busy</td></tr>",
+            min * 100.0,
+            mean * 100.0,
+            max * 100.0
+        )?;
+        writeln!(out, "</table>")?;
+        if let Some(aura) = &report.simulation_baseline {
+            writeln!(out, "<h3>Compared with {}</h3><table>", aura.consensus)?;
+            writeln!(out, "<tr><th></th><th>{}</th><th>{}</th></tr>", aura.consensus, sim.consensus)?;
+            for (label, values) in simulate::compare(&[aura, sim]) {
+                writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", label, values.join("</td><td>"))?;
+            }
+            writeln!(out, "</table>")?;
+        }
+        // At most ~48 bars, so long runs stay readable
+        let step = (sim.backlog.len() / 48).max(1);
+        let bars: Vec<(String, f64)> = sim
+            .backlog
+            .iter()
+            .step_by(step)
+            .map(|b| (format!("{:.0}", b.time_secs), b.pending as f64))
+            .collect();
+        // One bar per validator only while the bars stay readable
+        let load: Vec<(String, f64)> = match sim.load.len() {
+            1..=48 => sim.load.iter().enumerate().map(|(i, l)| (i.to_string(), l.utilization * 100.0)).collect(),
+            _ => Vec::new(),
+        };
+        if !bars.is_empty() || !load.is_empty() {
+            writeln!(out, "<div class=\"charts\">")?;
+            if !bars.is_empty() {
+                out.push_str(&bar_chart("Mempool backlog", "time (s)", "pending transactions", &bars));
+            }
+            if !load.is_empty() {
+                out.push_str(&bar_chart("Validator load", "validator", "% busy verifying and signing", &load));
+            }
+            writeln!(out, "</div>")?;
+        }
+    }
+    if let Some(real) = &report.real_tps {
+        writeln!(out, "<h2>Real TPS</h2><table>")?;
+        writeln!(out, "<tr><th>Submitted</th><td>{} ({} ok, {} failed)</td></tr>", real.submitted, real.successful, real.failed)?;
+        writeln!(out, "<tr><th>Submission TPS</th><td>{:.2}</td></tr>", real.submission_tps)?;
+        writeln!(out, "<tr><th>Effective TPS</th><td>{:.2}</td></tr>", real.effective_tps)?;
+        if let Some(direct) = &real.direct {
+            writeln!(
+                out,
+                "<tr><th>Direct submission</th><td>{} from {}, {} bytes each</td></tr>",
+                escape(&direct.scheme),
+                escape(&direct.signer),
+                direct.extrinsic_bytes
+            )?;
+            writeln!(
+                out,
+                "<tr><th>Node answers</th><td>{} accepted, {} rejected, {} failed</td></tr>",
+                direct.accepted, direct.rejected, direct.failed
+            )?;
+        }
+        writeln!(out, "</table>")?;
+        if let Some(direct) = real.direct.as_ref().filter(|d| !d.reasons.is_empty()) {
+            writeln!(out, "<table><tr><th>Rejections</th><th>Reason</th></tr>")?;
+            for reason in &direct.reasons {
+                writeln!(out, "<tr><td>{}</td><td>{}</td></tr>", reason.count, escape(&reason.reason))?;
+            }
+            writeln!(out, "</table>")?;
+        }
+        if let Some(inclusion) = &real.inclusion {
+            writeln!(
+                out,
+                "<h3>Transaction lifecycle</h3><p>{} tracked: {} ready, {} in block, {} finalized.</p>",
+                inclusion.tracked, inclusion.ready, inclusion.in_block, inclusion.finalized
+            )?;
+            writeln!(out, "<table><tr><th>Stage</th><th>Count</th><th>p50 (s)</th><th>p95 (s)</th><th>p99 (s)</th><th>Max (s)</th></tr>")?;
+            for (stage, row) in [
+                ("Submit → ready", &inclusion.submit_to_ready),
+                ("Submit → in block", &inclusion.submit_to_in_block),
+                ("Submit → finalized", &inclusion.submit_to_finalized),
+                ("In block → finalized", &inclusion.in_block_to_finalized),
+            ]
+            .into_iter()
+            .filter(|(_, row)| row.count > 0)
+            {
+                writeln!(
+                    out,
+                    "<tr><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.2}</td><td>{:.2}</td><td>{:.2}</td></tr>",
+                    stage,
+                    row.count,
+                    row.p50_ms / 1000.0,
+                    row.p95_ms / 1000.0,
+                    row.p99_ms / 1000.0,
+                    row.max_ms / 1000.0
+                )?;
+            }
+            writeln!(out, "</table>")?;
+            if !inclusion.missing.is_empty() {
+                writeln!(out, "<p>Never landed in a block:</p><ul>")?;
+                for missing in &inclusion.missing {
+                    writeln!(out, "<li><code>{}</code> ({})</li>", escape(&missing.hash), escape(&missing.status))?;
+                }
+                writeln!(out, "</ul>")?;
+            }
+            if !inclusion.skipped_blocks.is_empty() {
+                writeln!(out, "<p>Blocks that could not be scanned:</p><ul>")?;
+                for skipped in &inclusion.skipped_blocks {
+                    writeln!(out, "<li>#{} ({})</li>", skipped.number, escape(&skipped.error))?;
+                }
+                writeln!(out, "</ul>")?;
+            }
+        }
+        if let Some(curve) = &real.load {
+            writeln!(
+                out,
+                "<h3>Offered vs included load</h3><p>{} over {:.0} s, {:.1} s windows, {:.1} s blocks. {}</p>",
+                escape(&curve.profile),
+                curve.duration_secs,
+                curve.window_secs,
+                curve.block_secs,
+                escape(&curve.summary())
+            )?;
+            writeln!(
+                out,
+                "<table><tr><th>Window (s)</th><th>Offered TPS</th><th>Accepted TPS</th><th>Included TPS</th><th>Landed</th><th>p50 inclusion (s)</th></tr>"
+            )?;
+            for w in &curve.windows {
+                writeln!(
+                    out,
+                    "<tr><td>{:.1}–{:.1}</td><td class=\"num\">{:.1}</td><td class=\"num\">{:.1}</td><td class=\"num\">{:.1}</td><td class=\"num\">{:.0}%</td><td class=\"num\">{:.2}</td></tr>",
+                    w.start_secs,
+                    w.end_secs,
+                    w.offered_tps,
+                    w.accepted_tps,
+                    w.included_tps,
+                    w.landed * 100.0,
+                    w.inclusion_p50_ms / 1000.0
+                )?;
+            }
+            writeln!(out, "</table>")?;
+            let point = |w: &LoadWindow, y: f64| ((w.start_secs + w.end_secs) / 2.0, y);
+            let series = [
+                Series {
+                    name: "Offered".to_string(),
+                    points: curve.windows.iter().map(|w| point(w, w.offered_tps)).collect(),
+                    color: PALETTE[1],
+                    dashed: true,
+                },
+                Series {
+                    name: "Included".to_string(),
+                    points: curve.windows.iter().map(|w| point(w, w.included_tps)).collect(),
+                    color: PALETTE[0],
+                    dashed: false,
+                },
+            ];
+            writeln!(out, "<div class=\"charts\">")?;
+            out.push_str(&line_chart("Offered vs included load", "time (s)", "TPS", &series, false));
+            writeln!(out, "</div>")?;
+        }
+    }
+    Ok(())
+}
+
+/// Round `max` up to a 1/2/5 × 10^n step so axis ticks land on readable values
+fn nice_ceiling(max: f64) -> (f64, f64) {
+    if max <= 0.0 || !max.is_finite() {
+        return (1.0, 0.25);
+    }
+    let raw_step = max / 4.0;
+    let magnitude = 10f64.powf(raw_step.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|m| m * magnitude)
+        .find(|&s| s >= raw_step)
+        .unwrap_or(10.0 * magnitude);
+    ((max / step).ceil() * step, step)
+}
+
+fn svg_open(out: &mut String, title: &str, x_label: &str, y_label: &str) {
+    let _ = write!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-size=\"11\">\
+         <text x=\"{cx}\" y=\"20\" text-anchor=\"middle\" font-size=\"14\" font-weight=\"bold\">{title}</text>\
+         <text x=\"{cx}\" y=\"{xl}\" text-anchor=\"middle\">{x_label}</text>\
+         <text x=\"16\" y=\"{cy}\" text-anchor=\"middle\" transform=\"rotate(-90 16 {cy})\">{y_label}</text>",
+        w = WIDTH,
+        h = HEIGHT,
+        cx = (MARGIN_LEFT + WIDTH - MARGIN_RIGHT) / 2.0,
+        cy = (MARGIN_TOP + HEIGHT - MARGIN_BOTTOM) / 2.0,
+        xl = HEIGHT - 8.0,
+        title = escape(title),
+        x_label = escape(x_label),
+        y_label = escape(y_label),
+    );
+}
+
+/// Horizontal grid lines and labels for a 0..=`y_max` axis; returns the y mapping
+fn y_axis(out: &mut String, y_max: f64) -> impl Fn(f64) -> f64 {
+    let (top, step) = nice_ceiling(y_max);
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let map = move |y: f64| HEIGHT - MARGIN_BOTTOM - y / top * plot_height;
+    let mut tick = 0.0;
+    while tick <= top + step / 2.0 {
+        let _ = write!(
+            out,
+            "<line x1=\"{x1}\" x2=\"{x2}\" y1=\"{y:.1}\" y2=\"{y:.1}\" stroke=\"#e5e7eb\"/>\
+             <text x=\"{tx}\" y=\"{ty:.1}\" text-anchor=\"end\">{label}</text>",
+            x1 = MARGIN_LEFT,
+            x2 = WIDTH - MARGIN_RIGHT,
+            y = map(tick),
+            tx = MARGIN_LEFT - 6.0,
+            ty = map(tick) + 4.0,
+            label = format_tick(tick, step),
+        );
+        tick += step;
+    }
+    map
+}
+
+fn format_tick(v: f64, step: f64) -> String {
+    let decimals = if step >= 1.0 { 0 } else { (-step.log10().floor()) as usize };
+    format!("{:.*}", decimals, v)
+}
+
+/// Line chart over a log2 x axis (segment and thread counts usually double), or a linear one
+fn line_chart(title: &str, x_label: &str, y_label: &str, series: &[Series], log_x: bool) -> String {
+    let mut out = String::new();
+    svg_open(&mut out, title, x_label, y_label);
+
+    let scale = |x: f64| if log_x { x.max(1.0).log2() } else { x };
+    let xs: Vec<f64> = series.iter().flat_map(|s| s.points.iter().map(|p| scale(p.0))).collect();
+    let (x_min, x_max) = xs.iter().fold((f64::MAX, f64::MIN), |(lo, hi), &x| (lo.min(x), hi.max(x)));
+    let x_span = if log_x { (x_max - x_min).max(1.0) } else { (x_max - x_min).max(f64::EPSILON) };
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let map_x = |x: f64| MARGIN_LEFT + (scale(x) - x_min) / x_span * plot_width;
+    let y_max = series.iter().flat_map(|s| s.points.iter().map(|p| p.1)).fold(0.0, f64::max);
+    let map_y = y_axis(&mut out, y_max);
+
+    if let Some(first) = series.first() {
+        for &(x, _) in &first.points {
+            let _ = write!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                map_x(x),
+                HEIGHT - MARGIN_BOTTOM + 16.0,
+                if log_x { x.to_string() } else { format!("{:.0}", x) }
+            );
+        }
+    }
+    for (i, s) in series.iter().enumerate() {
+        let points: Vec<String> = s.points.iter().map(|&(x, y)| format!("{:.1},{:.1}", map_x(x), map_y(y))).collect();
+        let dash = if s.dashed { " stroke-dasharray=\"6 4\"" } else { "" };
+        let _ = write!(
+            out,
+            "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"{}/>",
+            points.join(" "),
+            s.color,
+            dash
+        );
+        if !s.dashed {
+            for &(x, y) in &s.points {
+                let _ = write!(out, "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"3\" fill=\"{}\"/>", map_x(x), map_y(y), s.color);
+            }
+        }
+        // Legend, top left of the plot area
+        let ly = MARGIN_TOP + 12.0 + i as f64 * 14.0;
+        let _ = write!(
+            out,
+            "<line x1=\"{x1}\" x2=\"{x2}\" y1=\"{y:.1}\" y2=\"{y:.1}\" stroke=\"{c}\" stroke-width=\"2\"{dash}/>\
+             <text x=\"{tx}\" y=\"{ty:.1}\">{name}</text>",
+            x1 = MARGIN_LEFT + 10.0,
+            x2 = MARGIN_LEFT + 30.0,
+            y = ly - 4.0,
+            c = s.color,
+            tx = MARGIN_LEFT + 36.0,
+            ty = ly,
+            name = escape(&s.name),
+        );
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// Vertical bar chart; labels are thinned out when there are many bars
+fn bar_chart(title: &str, x_label: &str, y_label: &str, bars: &[(String, f64)]) -> String {
+    let mut out = String::new();
+    svg_open(&mut out, title, x_label, y_label);
+    let map_y = y_axis(&mut out, bars.iter().map(|b| b.1).fold(0.0, f64::max));
+    let slot = (WIDTH - MARGIN_LEFT - MARGIN_RIGHT) / bars.len().max(1) as f64;
+    let label_every = (bars.len() / 12).max(1);
+    for (i, (label, value)) in bars.iter().enumerate() {
+        let x = MARGIN_LEFT + i as f64 * slot;
+        let y = map_y(*value);
+        let _ = write!(
+            out,
+            "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"><title>{}: {}</title></rect>",
+            x + slot * 0.1,
+            y,
+            (slot * 0.8).max(0.5),
+            HEIGHT - MARGIN_BOTTOM - y,
+            PALETTE[0],
+            escape(label),
+            value
+        );
+        if i % label_every == 0 {
+            let _ = write!(
+                out,
+                "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+                x + slot / 2.0,
+                HEIGHT - MARGIN_BOTTOM + 16.0,
+                escape(label)
+            );
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
@@ -0,0 +1,498 @@
+//! Transaction inclusion tracking
+//!
+//! Follows every submitted transaction by hash from submission to finality.
+//! `--direct` submits with `author_submitAndWatchExtrinsic`, whose status
+//! events say when the transaction entered the pool (`ready`), which block
+//! included it (`inBlock`) and when that block was finalized. Faucet drips
+//! only hand back a hash, so [`scan`] follows the chain instead: each new
+//! block body is hashed extrinsic by extrinsic (BLAKE2b-256 of the encoding,
+//! the same hash the pool uses) and finality comes from the finalized heads.
+//! Scanned transactions have no ready time.
+//!
+//! Times are seconds since the submission run started, taken when a
+//! notification came off the socket.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+use crate::extrinsic::blake2_256;
+use crate::heads::header_number;
+use crate::latency::LatencyHistogram;
+use crate::report::LatencyRow;
+use crate::ws::WsClient;
+
+/// One `author_submitAndWatchExtrinsic` status event
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TxStatus {
+    Future,
+    Ready,
+    Broadcast,
+    InBlock(String),
+    Retracted(String),
+    FinalityTimeout(String),
+    Finalized(String),
+    Usurped(String),
+    Dropped,
+    Invalid,
+}
+
+impl TxStatus {
+    /// Parse a status event: a bare string (`"ready"`) or an object carrying a block hash (`{"inBlock": "0x…"}`)
+    pub fn parse(event: &Value) -> Option<Self> {
+        if let Some(name) = event.as_str() {
+            return match name {
+                "future" => Some(TxStatus::Future),
+                "ready" => Some(TxStatus::Ready),
+                "dropped" => Some(TxStatus::Dropped),
+                "invalid" => Some(TxStatus::Invalid),
+                _ => None,
+            };
+        }
+        let (name, value) = event.as_object()?.iter().next()?;
+        let block = || value.as_str().map(str::to_string);
+        match name.as_str() {
+            "broadcast" => Some(TxStatus::Broadcast),
+            "inBlock" => block().map(TxStatus::InBlock),
+            "retracted" => block().map(TxStatus::Retracted),
+            "finalityTimeout" => block().map(TxStatus::FinalityTimeout),
+            "finalized" => block().map(TxStatus::Finalized),
+            "usurped" => block().map(TxStatus::Usurped),
+            _ => None,
+        }
+    }
+
+    /// Whether the node sends no more events after this one
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            TxStatus::Finalized(_)
+                | TxStatus::FinalityTimeout(_)
+                | TxStatus::Usurped(_)
+                | TxStatus::Dropped
+                | TxStatus::Invalid
+        )
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TxStatus::Future => "future",
+            TxStatus::Ready => "ready",
+            TxStatus::Broadcast => "broadcast",
+            TxStatus::InBlock(_) => "in block",
+            TxStatus::Retracted(_) => "retracted",
+            TxStatus::FinalityTimeout(_) => "finality timeout",
+            TxStatus::Finalized(_) => "finalized",
+            TxStatus::Usurped(_) => "usurped",
+            TxStatus::Dropped => "dropped",
+            TxStatus::Invalid => "invalid",
+        }
+    }
+}
+
+/// The life of one transaction
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TxTrace {
+    /// `0x`-hex transaction hash
+    pub hash: String,
+    pub submitted_secs: f64,
+    pub ready_secs: Option<f64>,
+    pub in_block_secs: Option<f64>,
+    /// Hash of the block that included it
+    pub block: Option<String>,
+    pub finalized_secs: Option<f64>,
+    /// Last status seen, `submitted` before any
+    pub status: String,
+}
+
+impl TxTrace {
+    pub fn new(hash: String, submitted_secs: f64) -> Self {
+        Self {
+            hash,
+            submitted_secs,
+            ready_secs: None,
+            in_block_secs: None,
+            block: None,
+            finalized_secs: None,
+            status: "submitted".to_string(),
+        }
+    }
+
+    /// Record `status`, seen `at_secs`
+    pub fn apply(&mut self, status: &TxStatus, at_secs: f64) {
+        match status {
+            TxStatus::Ready => {
+                self.ready_secs.get_or_insert(at_secs);
+            }
+            TxStatus::InBlock(block) => {
+                self.in_block_secs = Some(at_secs);
+                self.block = Some(block.clone());
+            }
+            // Back in the pool; a later inBlock says where it landed instead
+            TxStatus::Retracted(_) => {
+                self.in_block_secs = None;
+                self.block = None;
+            }
+            TxStatus::Finalized(block) => {
+                self.in_block_secs.get_or_insert(at_secs);
+                self.block = Some(block.clone());
+                self.finalized_secs = Some(at_secs);
+            }
+            _ => {}
+        }
+        self.status = status.name().to_string();
+    }
+}
+
+/// A transaction that never made it into a block
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MissingTx {
+    pub hash: String,
+    /// Last status seen
+    pub status: String,
+}
+
+/// Stage latencies over all tracked transactions
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InclusionStats {
+    pub tracked: usize,
+    pub ready: usize,
+    pub in_block: usize,
+    pub finalized: usize,
+    pub submit_to_ready: LatencyRow,
+    pub submit_to_in_block: LatencyRow,
+    pub submit_to_finalized: LatencyRow,
+    pub in_block_to_finalized: LatencyRow,
+    /// Transactions never seen in a block
+    pub missing: Vec<MissingTx>,
+    /// Blocks the scan could not fetch; transactions in them show as missing
+    #[serde(default)]
+    pub skipped_blocks: Vec<SkippedBlock>,
+}
+
+/// A block [`scan`] could not fetch
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SkippedBlock {
+    pub number: u64,
+    pub error: String,
+}
+
+impl InclusionStats {
+    pub fn from_traces(traces: &[TxTrace]) -> Self {
+        let histogram = |from: fn(&TxTrace) -> Option<f64>, to: fn(&TxTrace) -> Option<f64>| {
+            let mut histogram = LatencyHistogram::new();
+            for trace in traces {
+                if let (Some(from), Some(to)) = (from(trace), to(trace)) {
+                    histogram.record(((to - from).max(0.0) * 1e9) as u64);
+                }
+            }
+            LatencyRow::from(&histogram)
+        };
+        let submitted = |t: &TxTrace| Some(t.submitted_secs);
+        Self {
+            tracked: traces.len(),
+            ready: traces.iter().filter(|t| t.ready_secs.is_some()).count(),
+            in_block: traces.iter().filter(|t| t.in_block_secs.is_some()).count(),
+            finalized: traces.iter().filter(|t| t.finalized_secs.is_some()).count(),
+            submit_to_ready: histogram(submitted, |t| t.ready_secs),
+            submit_to_in_block: histogram(submitted, |t| t.in_block_secs),
+            submit_to_finalized: histogram(submitted, |t| t.finalized_secs),
+            in_block_to_finalized: histogram(|t| t.in_block_secs, |t| t.finalized_secs),
+            missing: traces
+                .iter()
+                .filter(|t| t.in_block_secs.is_none())
+                .map(|t| MissingTx { hash: t.hash.clone(), status: t.status.clone() })
+                .collect(),
+            skipped_blocks: Vec::new(),
+        }
+    }
+}
+
+/// Follow the chain on `url` after block `from` and trace the transactions `hashes` delivers
+///
+/// `hashes` gets the submitted (hash, submitted seconds) pairs once submission
+/// is over; blocks are scanned from the start so transactions that landed in
+/// the meantime are found too. Scanning stops when all of them are in a
+/// finalized block, or `confirm` after the hashes arrived. A block that cannot
+/// be fetched is skipped and returned with the error.
+pub async fn scan(
+    url: &str,
+    start: Instant,
+    from: u64,
+    mut hashes: oneshot::Receiver<Vec<(String, f64)>>,
+    confirm: Duration,
+) -> Result<(Vec<TxTrace>, Vec<SkippedBlock>), String> {
+    let mut client = WsClient::connect(url).await?;
+    let new_heads = client.subscribe("chain_subscribeNewHeads", vec![]).await?;
+    let finalized_heads = client.subscribe("chain_subscribeFinalizedHeads", vec![]).await?;
+
+    let mut next = from + 1;
+    // Transaction hash to (block number, block hash, seen at)
+    let mut landed: HashMap<String, (u64, String, f64)> = HashMap::new();
+    // (seen at, finalized number), in arrival order
+    let mut finality: Vec<(f64, u64)> = Vec::new();
+    let mut skipped = Vec::new();
+    let mut submitted: Option<Vec<(String, f64)>> = None;
+    let mut deadline = None;
+    loop {
+        if submitted.is_none() {
+            match hashes.try_recv() {
+                Ok(list) => submitted = Some(list.into_iter().map(|(hash, at)| (normalize(&hash), at)).collect()),
+                Err(oneshot::error::TryRecvError::Closed) => submitted = Some(Vec::new()),
+                Err(oneshot::error::TryRecvError::Empty) => {}
+            }
+            if submitted.is_some() {
+                deadline = Some(Instant::now() + confirm);
+            }
+        }
+        if let (Some(list), Some(&(_, finalized))) = (&submitted, finality.last()) {
+            if list.iter().all(|(hash, _)| landed.get(hash).is_some_and(|&(number, ..)| number <= finalized)) {
+                break;
+            }
+        }
+        if deadline.is_some_and(|d| Instant::now() >= d) {
+            break;
+        }
+
+        let Ok(notification) = tokio::time::timeout(Duration::from_millis(250), client.next_notification()).await
+        else {
+            continue;
+        };
+        let notification = notification?;
+        let at = notification.received.duration_since(start).as_secs_f64();
+        let number = header_number(&notification.result)?;
+        if notification.subscription == new_heads {
+            // Heads can skip numbers; catch up on every block in between
+            for block_number in next..=number {
+                let (block_hash, extrinsics) = match fetch_block(&mut client, block_number).await {
+                    Ok(block) => block,
+                    Err(error) => {
+                        skipped.push(SkippedBlock { number: block_number, error });
+                        continue;
+                    }
+                };
+                for extrinsic in extrinsics.iter().filter_map(Value::as_str) {
+                    let Ok(bytes) = hex::decode(extrinsic.trim_start_matches("0x")) else { continue };
+                    let hash = format!("0x{}", hex::encode(blake2_256(&bytes)));
+                    landed.entry(hash).or_insert((block_number, block_hash.clone(), at));
+                }
+            }
+            next = next.max(number + 1);
+        } else if notification.subscription == finalized_heads {
+            finality.push((at, number));
+        }
+    }
+    client.close().await;
+
+    let traces = submitted
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(hash, submitted_secs)| {
+            let mut trace = TxTrace::new(hash, submitted_secs);
+            if let Some((number, block, seen)) = landed.get(&trace.hash) {
+                trace.apply(&TxStatus::InBlock(block.clone()), *seen);
+                if let Some(&(at, _)) = finality.iter().find(|&&(_, finalized)| finalized >= *number) {
+                    trace.apply(&TxStatus::Finalized(block.clone()), at);
+                }
+            }
+            trace
+        })
+        .collect();
+    Ok((traces, skipped))
+}
+
+/// Hash and extrinsics of block `number`
+async fn fetch_block(client: &mut WsClient, number: u64) -> Result<(String, Vec<Value>), String> {
+    let block_hash = client.request("chain_getBlockHash", vec![number.into()]).await?;
+    // A null hash would fetch the best block instead
+    let block_hash = block_hash.as_str().ok_or("node has no hash for this block")?.to_string();
+    let block = client.request("chain_getBlock", vec![block_hash.clone().into()]).await?;
+    let extrinsics = block.pointer("/block/extrinsics").and_then(Value::as_array).cloned().unwrap_or_default();
+    Ok((block_hash, extrinsics))
+}
+
+/// Lowercase `0x`-hex, as the scan computes hashes
+fn normalize(hash: &str) -> String {
+    format!("0x{}", hash.trim_start_matches("0x").to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{SinkExt, StreamExt};
+    use serde_json::json;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    fn trace(events: &[(TxStatus, f64)]) -> TxTrace {
+        let mut trace = TxTrace::new("0x01".to_string(), 1.0);
+        for (status, at) in events {
+            trace.apply(status, *at);
+        }
+        trace
+    }
+
+    #[test]
+    fn parses_status_events() {
+        assert_eq!(TxStatus::parse(&json!("ready")), Some(TxStatus::Ready));
+        assert_eq!(TxStatus::parse(&json!("future")), Some(TxStatus::Future));
+        assert_eq!(TxStatus::parse(&json!("dropped")), Some(TxStatus::Dropped));
+        assert_eq!(TxStatus::parse(&json!("invalid")), Some(TxStatus::Invalid));
+        assert_eq!(TxStatus::parse(&json!({ "broadcast": ["peer"] })), Some(TxStatus::Broadcast));
+        assert_eq!(TxStatus::parse(&json!({ "inBlock": "0xaa" })), Some(TxStatus::InBlock("0xaa".into())));
+        assert_eq!(TxStatus::parse(&json!({ "retracted": "0xaa" })), Some(TxStatus::Retracted("0xaa".into())));
+        assert_eq!(TxStatus::parse(&json!({ "finalized": "0xbb" })), Some(TxStatus::Finalized("0xbb".into())));
+        assert_eq!(
+            TxStatus::parse(&json!({ "finalityTimeout": "0xbb" })),
+            Some(TxStatus::FinalityTimeout("0xbb".into()))
+        );
+        assert_eq!(TxStatus::parse(&json!({ "usurped": "0xcc" })), Some(TxStatus::Usurped("0xcc".into())));
+        assert!(TxStatus::parse(&json!({ "finalized": "0xbb" })).unwrap().is_final());
+        assert!(!TxStatus::parse(&json!({ "inBlock": "0xaa" })).unwrap().is_final());
+    }
+
+    #[test]
+    fn rejects_unknown_status_events() {
+        assert_eq!(TxStatus::parse(&json!("pending")), None);
+        assert_eq!(TxStatus::parse(&json!({ "included": "0xaa" })), None);
+        // A block status needs a block hash
+        assert_eq!(TxStatus::parse(&json!({ "inBlock": 5 })), None);
+        assert_eq!(TxStatus::parse(&json!({})), None);
+        assert_eq!(TxStatus::parse(&json!(null)), None);
+    }
+
+    #[test]
+    fn traces_ready_in_block_and_finalized() {
+        let trace = trace(&[
+            (TxStatus::Ready, 1.1),
+            // A second ready does not move the first one
+            (TxStatus::Ready, 1.5),
+            (TxStatus::InBlock("0xaa".into()), 4.0),
+            (TxStatus::Finalized("0xaa".into()), 10.0),
+        ]);
+        assert_eq!(trace.ready_secs, Some(1.1));
+        assert_eq!(trace.in_block_secs, Some(4.0));
+        assert_eq!(trace.finalized_secs, Some(10.0));
+        assert_eq!(trace.block.as_deref(), Some("0xaa"));
+        assert_eq!(trace.status, "finalized");
+    }
+
+    #[test]
+    fn retracted_then_reincluded_keeps_the_later_block() {
+        let trace = trace(&[
+            (TxStatus::Ready, 1.1),
+            (TxStatus::InBlock("0xaa".into()), 4.0),
+            (TxStatus::Retracted("0xaa".into()), 5.0),
+        ]);
+        assert_eq!((trace.in_block_secs, trace.block.as_deref()), (None, None));
+        assert_eq!(trace.status, "retracted");
+
+        let mut trace = trace;
+        trace.apply(&TxStatus::InBlock("0xbb".into()), 7.0);
+        trace.apply(&TxStatus::Finalized("0xbb".into()), 13.0);
+        assert_eq!(trace.in_block_secs, Some(7.0));
+        assert_eq!(trace.block.as_deref(), Some("0xbb"));
+        assert_eq!(trace.finalized_secs, Some(13.0));
+    }
+
+    #[test]
+    fn finalized_without_in_block_counts_as_included_then() {
+        let trace = trace(&[(TxStatus::Ready, 1.1), (TxStatus::Finalized("0xaa".into()), 9.0)]);
+        assert_eq!(trace.in_block_secs, Some(9.0));
+        assert_eq!(trace.finalized_secs, Some(9.0));
+        assert_eq!(trace.block.as_deref(), Some("0xaa"));
+    }
+
+    #[test]
+    fn stats_count_stages_and_list_missing() {
+        let traces = vec![
+            trace(&[
+                (TxStatus::Ready, 1.5),
+                (TxStatus::InBlock("0xaa".into()), 3.0),
+                (TxStatus::Finalized("0xaa".into()), 7.0),
+            ]),
+            trace(&[(TxStatus::Ready, 2.0), (TxStatus::InBlock("0xaa".into()), 5.0)]),
+            trace(&[(TxStatus::Ready, 1.5), (TxStatus::InBlock("0xaa".into()), 3.0), (TxStatus::Retracted("0xaa".into()), 4.0)]),
+            trace(&[(TxStatus::Invalid, 1.2)]),
+            TxTrace::new("0x02".to_string(), 1.0),
+        ];
+        let stats = InclusionStats::from_traces(&traces);
+        assert_eq!((stats.tracked, stats.ready, stats.in_block, stats.finalized), (5, 3, 2, 1));
+        assert_eq!(stats.submit_to_ready.count, 3);
+        assert!((stats.submit_to_ready.max_ms - 1000.0).abs() < 1.0);
+        assert_eq!(stats.submit_to_in_block.count, 2);
+        assert!((stats.submit_to_in_block.max_ms - 4000.0).abs() < 4.0);
+        assert_eq!(stats.submit_to_finalized.count, 1);
+        assert!((stats.submit_to_finalized.p50_ms - 6000.0).abs() < 6.0);
+        assert!((stats.in_block_to_finalized.p50_ms - 4000.0).abs() < 4.0);
+
+        let missing: Vec<&str> = stats.missing.iter().map(|m| m.status.as_str()).collect();
+        assert_eq!(missing, ["retracted", "invalid", "submitted"]);
+        assert!(stats.skipped_blocks.is_empty());
+    }
+
+    /// A node with blocks #1 and #2 that fails to return block #1; #2 holds extrinsic `0x0102`
+    async fn node_missing_block_one() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(Message::Text(text))) = socket.next().await {
+                let call: Value = serde_json::from_str(&text).unwrap();
+                let id = call["id"].clone();
+                let (result, then) = match (call["method"].as_str().unwrap(), call["params"][0].clone()) {
+                    ("chain_subscribeNewHeads", _) => (Ok(json!("new")), None),
+                    ("chain_subscribeFinalizedHeads", _) => (Ok(json!("finalized")), Some("0x2")),
+                    ("chain_getBlockHash", number) if number == json!(2) => (Ok(json!("0xb2")), None),
+                    ("chain_getBlock", hash) if hash == json!("0xb2") => {
+                        (Ok(json!({ "block": { "extrinsics": ["0x0102"] } })), None)
+                    }
+                    _ => (Err(json!({ "code": -32000, "message": "state already discarded" })), None),
+                };
+                let answer = match result {
+                    Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+                    Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+                };
+                socket.send(Message::Text(answer.to_string())).await.unwrap();
+                if let Some(number) = then {
+                    for subscription in ["new", "finalized"] {
+                        let header = json!({ "number": number, "parentHash": "0x00" });
+                        let notification = json!({
+                            "jsonrpc": "2.0",
+                            "method": "chain_subscription",
+                            "params": { "subscription": subscription, "result": header },
+                        });
+                        socket.send(Message::Text(notification.to_string())).await.unwrap();
+                    }
+                }
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn scan_skips_blocks_it_cannot_fetch() {
+        let landed = format!("0x{}", hex::encode(blake2_256(&[1, 2])));
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+        let (traces, skipped) = runtime
+            .block_on(async {
+                let url = node_missing_block_one().await;
+                let (hashes, receiver) = oneshot::channel();
+                // Submitted hashes are matched case-insensitively
+                hashes.send(vec![(landed.to_uppercase().replacen("0X", "0x", 1), 0.0)]).unwrap();
+                scan(&url, Instant::now(), 0, receiver, Duration::from_secs(5)).await
+            })
+            .unwrap();
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].number, 1);
+        assert!(skipped[0].error.contains("state already discarded"), "{}", skipped[0].error);
+        // Block #2 was still scanned, and its transaction found and finalized
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].hash, landed);
+        assert_eq!(traces[0].block.as_deref(), Some("0xb2"));
+        assert!(traces[0].finalized_secs.is_some());
+    }
+}
@@ -0,0 +1,162 @@
+//! JUnit XML report writer
+//!
+//! CI dashboards understand JUnit, so a run is rendered as test suites:
+//! one test case per assertion, one per measured strategy (failing if any
+//! signature did not verify), and one for a network run (failing if no
+//! validator answered). A case's time is the mean wall time of one run.
+
+use std::fmt::Write;
+
+use crate::report::BenchmarkReport;
+
+struct TestCase {
+    classname: String,
+    name: String,
+    time_secs: f64,
+    failure: Option<String>,
+    output: String,
+}
+
+/// Render `report` as a JUnit XML document
+pub fn render(report: &BenchmarkReport) -> String {
+    let mut suites: Vec<(&str, Vec<TestCase>)> = Vec::new();
+
+    if !report.assertions.is_empty() {
+        let cases = report
+            .assertions
+            .iter()
+            .map(|a| {
+                let observed = match (a.actual, &a.worst_row) {
+                    (Some(actual), Some(row)) => format!("worst {:.3} ({})", actual, row),
+                    _ => "not measured".to_string(),
+                };
+                TestCase {
+                    classname: "assertions".to_string(),
+                    name: a.requirement.clone(),
+                    time_secs: 0.0,
+                    failure: (!a.passed).then(|| format!("{}: {}", a.requirement, observed)),
+                    output: observed,
+                }
+            })
+            .collect();
+        suites.push(("assertions", cases));
+    }
+
+    if !report.results.is_empty() {
+        let cases = report
+            .results
+            .iter()
+            .map(|row| TestCase {
+                classname: format!(
+                    "{}{}.{}.{}tx.{}b",
+                    row.suite.as_ref().map(|s| format!("{}.", s)).unwrap_or_default(),
+                    row.scheme,
+                    row.path,
+                    row.tx_count,
+                    row.payload_bytes
+                ),
+                name: format!("{} ({})", row.strategy, row.kind),
+                time_secs: if row.tps.mean > 0.0 { row.verified as f64 / row.tps.mean } else { 0.0 },
+                failure: (row.verified < row.total)
+                    .then(|| format!("only {} of {} signatures verified", row.verified, row.total)),
+                output: format!(
+                    "{:.2} TPS ±{:.2}, {:.2}x speedup, p99 {:.3} ms",
+                    row.tps.mean, row.tps.ci95, row.speedup, row.latency.p99_ms
+                ),
+            })
+            .collect();
+        suites.push(("strategies", cases));
+    }
+
+    if report.config.mode != "local" {
+        let output = if let Some(network) = &report.network {
+            format!("{} blocks, {:.2} s block time", network.blocks_produced, network.block_time_secs)
+        } else if let Some(real) = &report.real_tps {
+            format!("{}/{} submitted, {:.2} effective TPS", real.successful, real.submitted, real.effective_tps)
+        } else {
+            String::new()
+        };
+        let time_secs = report
+            .network
+            .as_ref()
+            .map(|n| n.elapsed_secs)
+            .or(report.real_tps.as_ref().map(|r| r.total_secs))
+            .unwrap_or(0.0);
+        let failure = match (&report.network, &report.real_tps) {
+            (None, None) => Some("no results: validators or faucet unreachable".to_string()),
+            (_, Some(real)) if real.successful == 0 => Some("no transaction was accepted".to_string()),
+            _ => None,
+        };
+        suites.push((
+            "network",
+            vec![TestCase {
+                classname: "network".to_string(),
+                name: report.config.mode.clone(),
+                time_secs,
+                failure,
+                output,
+            }],
+        ));
+    }
+
+    let mut out = String::new();
+    let _ = write_suites(&mut out, report, &suites);
+    out
+}
+
+fn write_suites(out: &mut String, report: &BenchmarkReport, suites: &[(&str, Vec<TestCase>)]) -> std::fmt::Result {
+    let env = &report.environment;
+    let properties = [
+        ("cpu_model", env.cpu_model.clone().unwrap_or_default()),
+        ("logical_cores", env.logical_cores.to_string()),
+        ("git_commit", env.build.git_commit.clone()),
+        ("profile", env.build.profile.clone()),
+    ];
+    let count = |f: fn(&TestCase) -> bool| suites.iter().flat_map(|(_, cases)| cases).filter(|c| f(c)).count();
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<testsuites name="sphincs-benchmark" tests="{}" failures="{}">"#,
+        count(|_| true),
+        count(|c| c.failure.is_some())
+    )?;
+    for (name, cases) in suites {
+        writeln!(
+            out,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" time="{:.6}">"#,
+            name,
+            cases.len(),
+            cases.iter().filter(|c| c.failure.is_some()).count(),
+            cases.iter().map(|c| c.time_secs).sum::<f64>()
+        )?;
+        writeln!(out, "    <properties>")?;
+        for (key, value) in &properties {
+            writeln!(out, r#"      <property name="{}" value="{}"/>"#, key, escape(value))?;
+        }
+        writeln!(out, "    </properties>")?;
+        for case in cases {
+            writeln!(
+                out,
+                r#"    <testcase classname="{}" name="{}" time="{:.6}">"#,
+                escape(&case.classname),
+                escape(&case.name),
+                case.time_secs
+            )?;
+            if let Some(failure) = &case.failure {
+                writeln!(out, r#"      <failure message="{}"/>"#, escape(failure))?;
+            }
+            writeln!(out, "      <system-out>{}</system-out>", escape(&case.output))?;
+            writeln!(out, "    </testcase>")?;
+        }
+        writeln!(out, "  </testsuite>")?;
+    }
+    writeln!(out, "</testsuites>")
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
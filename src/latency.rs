@@ -0,0 +1,178 @@
+//! Per-signature verification latency
+//!
+//! Batch throughput hides the tail: a strategy can have good TPS while some
+//! signatures wait much longer than others. Every `verify` call is timed and
+//! recorded into an HDR histogram (1 ns to 24 h, 3 significant digits).
+//! Network latencies (inclusion, finality, block times) use the same
+//! histogram, so every histogram has the same bounds and any two can be
+//! merged.
+
+use hdrhistogram::Histogram;
+use std::time::Duration;
+
+/// Longest latency a histogram tells apart; longer ones are recorded as this
+pub const MAX_LATENCY: Duration = Duration::from_secs(24 * 3600);
+
+/// HDR histogram of verify latencies, in nanoseconds
+#[derive(Clone)]
+pub struct LatencyHistogram {
+    histogram: Histogram<u64>,
+}
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        let histogram =
+            Histogram::new_with_bounds(1, MAX_LATENCY.as_nanos() as u64, 3).expect("valid histogram bounds");
+        Self { histogram }
+    }
+
+    pub fn from_nanos(samples: impl IntoIterator<Item = u64>) -> Self {
+        let mut histogram = Self::new();
+        for nanos in samples {
+            histogram.record(nanos);
+        }
+        histogram
+    }
+
+    pub fn record(&mut self, nanos: u64) {
+        self.histogram.saturating_record(nanos.max(1));
+    }
+
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        // Every histogram is built by `new`, so the bounds always match
+        self.histogram.add(&other.histogram).expect("histograms share bounds");
+    }
+
+    pub fn count(&self) -> u64 {
+        self.histogram.len()
+    }
+
+    /// Latency at percentile `p` (0-100)
+    pub fn percentile(&self, p: f64) -> Duration {
+        Duration::from_nanos(self.histogram.value_at_quantile(p / 100.0))
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.histogram.max())
+    }
+
+    /// Counts in `n` equal-width buckets spanning min..=max
+    pub fn buckets(&self, n: usize) -> Vec<LatencyBucket> {
+        if self.count() == 0 || n == 0 {
+            return Vec::new();
+        }
+        let (lo, hi) = (self.histogram.min(), self.histogram.max());
+        let width = ((hi - lo) / n as u64).max(1);
+        let mut counts = vec![0u64; n];
+        for value in self.histogram.iter_recorded() {
+            let i = (value.value_iterated_to().saturating_sub(lo) / width).min(n as u64 - 1);
+            counts[i as usize] += value.count_at_value();
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| LatencyBucket {
+                lower: Duration::from_nanos(lo + i as u64 * width),
+                upper: Duration::from_nanos(lo + (i as u64 + 1) * width),
+                count,
+            })
+            .collect()
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        LatencySummary {
+            count: self.count(),
+            mean: Duration::from_nanos(self.histogram.mean() as u64),
+            p50: self.percentile(50.0),
+            p95: self.percentile(95.0),
+            p99: self.percentile(99.0),
+            max: self.max(),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One bar of a latency histogram
+#[derive(Clone, Copy, Debug)]
+pub struct LatencyBucket {
+    pub lower: Duration,
+    pub upper: Duration,
+    pub count: u64,
+}
+
+/// The percentiles we report
+#[derive(Clone, Copy, Debug)]
+pub struct LatencySummary {
+    pub count: u64,
+    pub mean: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl LatencySummary {
+    /// How far the tail is from the typical signature (1.0 = no tail)
+    pub fn tail_ratio(&self) -> f64 {
+        self.p99.as_secs_f64() / self.p50.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS: u64 = 1_000_000;
+
+    fn millis(d: Duration) -> f64 {
+        d.as_secs_f64() * 1e3
+    }
+
+    #[test]
+    fn percentiles_of_known_samples() {
+        let histogram = LatencyHistogram::from_nanos((1..=100).map(|i| i * MS));
+        assert_eq!(histogram.count(), 100);
+        // 3 significant digits: within 0.1% of the exact sample
+        assert!((millis(histogram.percentile(50.0)) - 50.0).abs() < 0.05);
+        assert!((millis(histogram.percentile(99.0)) - 99.0).abs() < 0.1);
+        assert!((millis(histogram.max()) - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn buckets_have_equal_width_and_hold_every_sample() {
+        let histogram = LatencyHistogram::from_nanos((1..=100).map(|i| i * MS));
+        let buckets = histogram.buckets(10);
+        assert_eq!(buckets.len(), 10);
+        let width = buckets[0].upper - buckets[0].lower;
+        assert!(buckets.iter().all(|b| b.upper - b.lower == width));
+        assert!(buckets.windows(2).all(|w| w[0].upper == w[1].lower));
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), 100);
+        assert!(buckets.iter().all(|b| b.count >= 9 && b.count <= 11));
+        assert!(LatencyHistogram::new().buckets(10).is_empty());
+    }
+
+    #[test]
+    fn merge_adds_samples() {
+        let mut fast = LatencyHistogram::from_nanos((1..=50).map(|i| i * MS));
+        let slow = LatencyHistogram::from_nanos((51..=100).map(|i| i * MS));
+        fast.merge(&slow);
+        assert_eq!(fast.count(), 100);
+        assert!((millis(fast.percentile(50.0)) - 50.0).abs() < 0.05);
+        assert!((millis(fast.max()) - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn merges_network_scale_latencies() {
+        // Finality lags of minutes merge with microsecond verify times
+        let mut verify = LatencyHistogram::from_nanos([10_000, 20_000]);
+        let lag = LatencyHistogram::from_nanos([600 * 1_000 * MS]);
+        verify.merge(&lag);
+        assert_eq!(verify.count(), 3);
+        assert!((verify.max().as_secs_f64() - 600.0).abs() < 1.0);
+    }
+}
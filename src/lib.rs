@@ -0,0 +1,30 @@
+//! QuantumHarmony SPHINCS+ TPS Benchmark library
+//!
+//! The `sphincs-benchmark` binary is a thin CLI over these modules. They are
+//! public so other tools can reuse the signature schemes and verification
+//! strategies, and consume the [`report`] types the binary writes with
+//! `--output json|csv`.
+
+pub mod assertions;
+pub mod baseline;
+pub mod capacity;
+pub mod environment;
+pub mod extrinsic;
+pub mod heads;
+pub mod html;
+pub mod inclusion;
+pub mod junit;
+pub mod latency;
+pub mod load;
+pub mod markdown;
+pub mod report;
+pub mod scalability;
+pub mod scenario;
+pub mod scheme;
+pub mod simulate;
+pub mod stats;
+pub mod strategy;
+pub mod submit;
+pub mod sweep;
+pub mod torus;
+pub mod ws;
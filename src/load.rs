@@ -0,0 +1,363 @@
+//! Open-loop load profiles
+//!
+//! `--load` replaces the one-shot burst of `--real-tps --direct` with a
+//! schedule: every transaction gets its send time up front from the profile
+//! and goes out at that time, whether or not earlier ones were answered. A
+//! closed loop (send, wait, send) slows down with the chain and so measures
+//! its own round trip; an open loop keeps offering the load and shows where
+//! the chain stops keeping up.
+//!
+//! Profiles give the offered rate in transactions per second over the run:
+//!
+//! - `constant:50`
+//! - `ramp:10..200`: linear from 10 to 200
+//! - `step:10..200:10`: 10, 20, …, 200, each held for an equal share of the run
+//! - `spike:20..200`: 20, with 200 for the middle tenth of the run
+//!
+//! Results are bucketed into windows by scheduled send time, at most 12 and
+//! none shorter than a block. Each window compares the offered rate with the
+//! rate transactions entered blocks, the share of its transactions that
+//! landed, and their submit → in-block latency. The saturation knee is the
+//! highest offered rate before the first window where the chain fell behind:
+//! under 95% landed, or a median inclusion latency over twice that of the best
+//! window and more than a block time above it (latency moves by up to a block
+//! with where in the slot a transaction arrives).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::inclusion::TxTrace;
+use crate::latency::LatencyHistogram;
+
+/// Share of a window's transactions that must land for the chain to count as keeping up
+const KEEPING_UP_LANDED: f64 = 0.95;
+/// Median inclusion latency, relative to the best window, beyond which the chain has fallen behind
+/// (if also more than a block time above it)
+const KEEPING_UP_LATENCY: f64 = 2.0;
+/// Most windows per run
+const WINDOWS: usize = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LoadProfile {
+    Constant { rate: f64 },
+    Ramp { from: f64, to: f64 },
+    Step { from: f64, to: f64, step: f64 },
+    Spike { base: f64, peak: f64 },
+}
+
+impl FromStr for LoadProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let rate = |v: &str| -> Result<f64, String> {
+            match v.trim().parse::<f64>() {
+                Ok(r) if r > 0.0 && r.is_finite() => Ok(r),
+                Ok(_) => Err(format!("rates must be positive in load profile '{}'", s)),
+                Err(e) => Err(format!("invalid rate '{}' in load profile '{}': {}", v.trim(), s, e)),
+            }
+        };
+        let range = |v: &str| -> Result<(f64, f64), String> {
+            let (from, to) = v
+                .split_once("..")
+                .ok_or_else(|| format!("expected FROM..TO in load profile '{}'", s))?;
+            Ok((rate(from)?, rate(to)?))
+        };
+
+        let (kind, spec) = s
+            .split_once(':')
+            .ok_or_else(|| format!("expected KIND:RATES in load profile '{}'", s))?;
+        match kind.trim() {
+            "constant" => Ok(LoadProfile::Constant { rate: rate(spec)? }),
+            "ramp" => {
+                let (from, to) = range(spec)?;
+                Ok(LoadProfile::Ramp { from, to })
+            }
+            "step" => {
+                let (rates, step) = spec
+                    .rsplit_once(':')
+                    .ok_or_else(|| format!("expected step:FROM..TO:STEP in load profile '{}'", s))?;
+                let (from, to) = range(rates)?;
+                if from > to {
+                    return Err(format!("step profile '{}' must go up", s));
+                }
+                Ok(LoadProfile::Step { from, to, step: rate(step)? })
+            }
+            "spike" => {
+                let (base, peak) = range(spec)?;
+                Ok(LoadProfile::Spike { base, peak })
+            }
+            other => Err(format!(
+                "unknown load profile '{}' (expected constant, ramp, step, spike)",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LoadProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LoadProfile::Constant { rate } => write!(f, "constant:{}", rate),
+            LoadProfile::Ramp { from, to } => write!(f, "ramp:{}..{}", from, to),
+            LoadProfile::Step { from, to, step } => write!(f, "step:{}..{}:{}", from, to, step),
+            LoadProfile::Spike { base, peak } => write!(f, "spike:{}..{}", base, peak),
+        }
+    }
+}
+
+impl LoadProfile {
+    /// Offered transactions per second at `t` seconds into a run of `duration` seconds
+    pub fn rate_at(&self, t: f64, duration: f64) -> f64 {
+        let progress = (t / duration).clamp(0.0, 1.0);
+        match *self {
+            LoadProfile::Constant { rate } => rate,
+            LoadProfile::Ramp { from, to } => from + (to - from) * progress,
+            LoadProfile::Step { from, to, step } => {
+                let steps = ((to - from) / step).floor() as usize + 1;
+                let current = ((progress * steps as f64) as usize).min(steps - 1);
+                from + step * current as f64
+            }
+            LoadProfile::Spike { base, peak } => {
+                if (0.45..0.55).contains(&progress) {
+                    peak
+                } else {
+                    base
+                }
+            }
+        }
+    }
+
+    /// Send times in seconds for a run of `duration` seconds
+    pub fn schedule(&self, duration: f64) -> Vec<f64> {
+        // Integrate the rate in 1 ms steps; a transaction is due each time the integral passes a whole one
+        let dt = 0.001;
+        let mut sends = Vec::new();
+        let mut owed = 0.0;
+        for i in 0..(duration / dt) as usize {
+            let t = i as f64 * dt;
+            owed += self.rate_at(t, duration) * dt;
+            while owed >= 1.0 {
+                sends.push(t);
+                owed -= 1.0;
+            }
+        }
+        sends
+    }
+}
+
+/// Offered against achieved load over one window of send time
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoadWindow {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    /// Transactions scheduled in the window, per second
+    pub offered_tps: f64,
+    /// Transactions the nodes accepted into their pools, per second
+    pub accepted_tps: f64,
+    /// Transactions that entered a block during the window, per second
+    pub included_tps: f64,
+    /// Share of the window's transactions that ever landed in a block
+    pub landed: f64,
+    /// Median submit → in block of the window's transactions, 0 when none landed
+    pub inclusion_p50_ms: f64,
+}
+
+/// How a `--load` run went
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoadCurve {
+    pub profile: String,
+    pub duration_secs: f64,
+    pub window_secs: f64,
+    /// Median interval between the blocks that included the run's transactions, 0 if fewer than two did
+    pub block_secs: f64,
+    /// Latest a transaction went out after its scheduled time
+    pub max_send_lag_ms: f64,
+    pub windows: Vec<LoadWindow>,
+    /// Highest offered rate before the chain first fell behind; `None` if it never did, or did from the start
+    pub knee_tps: Option<f64>,
+    /// Whether any window fell behind
+    pub saturated: bool,
+    pub peak_included_tps: f64,
+}
+
+impl LoadCurve {
+    /// Bucket a run: `sent` has each transaction's scheduled time and, if the node accepted it, its trace
+    pub fn measure(profile: &LoadProfile, duration: f64, sent: &[(f64, Option<&TxTrace>)]) -> Self {
+        // When each including block was first seen
+        let mut blocks: HashMap<&str, f64> = HashMap::new();
+        for (_, trace) in sent {
+            if let Some((Some(block), Some(at))) = trace.map(|t| (t.block.as_deref(), t.in_block_secs)) {
+                let first = blocks.entry(block).or_insert(at);
+                *first = first.min(at);
+            }
+        }
+        let mut seen: Vec<f64> = blocks.into_values().collect();
+        seen.sort_by(f64::total_cmp);
+        let mut gaps: Vec<f64> = seen.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        gaps.sort_by(f64::total_cmp);
+        let block_secs = gaps.get(gaps.len() / 2).copied().unwrap_or(0.0);
+
+        // Shorter windows than a block alternate between zero and whole blocks
+        let count = if block_secs > 0.0 { ((duration / block_secs) as usize).clamp(1, WINDOWS) } else { WINDOWS };
+        let window_secs = duration / count as f64;
+        let window_of = |t: f64| ((t / window_secs).max(0.0) as usize).min(count - 1);
+
+        let mut offered = vec![0usize; count];
+        let mut accepted = vec![0usize; count];
+        let mut included = vec![0usize; count];
+        let mut landed = vec![0usize; count];
+        let mut latency: Vec<LatencyHistogram> =
+            (0..count).map(|_| LatencyHistogram::new()).collect();
+        let mut max_send_lag: f64 = 0.0;
+        for &(scheduled, trace) in sent {
+            let w = window_of(scheduled);
+            offered[w] += 1;
+            let Some(trace) = trace else { continue };
+            accepted[w] += 1;
+            max_send_lag = max_send_lag.max(trace.submitted_secs - scheduled);
+            if let Some(in_block) = trace.in_block_secs {
+                landed[w] += 1;
+                latency[w].record(((in_block - trace.submitted_secs).max(0.0) * 1e9) as u64);
+                // Backlog cleared after the run still counts as landed, but not towards any window's rate
+                if in_block < duration {
+                    included[window_of(in_block)] += 1;
+                }
+            }
+        }
+
+        let windows: Vec<LoadWindow> = (0..count)
+            .map(|w| LoadWindow {
+                start_secs: w as f64 * window_secs,
+                end_secs: (w + 1) as f64 * window_secs,
+                offered_tps: offered[w] as f64 / window_secs,
+                accepted_tps: accepted[w] as f64 / window_secs,
+                included_tps: included[w] as f64 / window_secs,
+                landed: if offered[w] > 0 { landed[w] as f64 / offered[w] as f64 } else { 0.0 },
+                inclusion_p50_ms: if latency[w].count() > 0 {
+                    latency[w].percentile(50.0).as_secs_f64() * 1000.0
+                } else {
+                    0.0
+                },
+            })
+            .collect();
+
+        let best = windows
+            .iter()
+            .map(|w| w.inclusion_p50_ms)
+            .filter(|&p50| p50 > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let latency_limit = (KEEPING_UP_LATENCY * best).max(best + block_secs * 1000.0);
+        let keeping_up = |w: &LoadWindow| {
+            w.offered_tps == 0.0 || (w.landed >= KEEPING_UP_LANDED && w.inclusion_p50_ms <= latency_limit)
+        };
+        let behind = windows.iter().position(|w| !keeping_up(w));
+        let knee_tps = behind.and_then(|first| {
+            windows[..first].iter().map(|w| w.offered_tps).filter(|&tps| tps > 0.0).reduce(f64::max)
+        });
+
+        Self {
+            profile: profile.to_string(),
+            duration_secs: duration,
+            window_secs,
+            block_secs,
+            max_send_lag_ms: max_send_lag * 1000.0,
+            peak_included_tps: windows.iter().map(|w| w.included_tps).fold(0.0, f64::max),
+            windows,
+            knee_tps,
+            saturated: behind.is_some(),
+        }
+    }
+
+    /// Where the chain stopped keeping up, in one sentence
+    pub fn summary(&self) -> String {
+        match self.knee_tps {
+            Some(knee) => format!(
+                "Saturation knee at ~{:.1} TPS offered; peak {:.1} TPS included.",
+                knee, self.peak_included_tps
+            ),
+            None if self.saturated => {
+                format!("Behind from the first window; peak {:.1} TPS included.", self.peak_included_tps)
+            }
+            None => format!("Kept up with the whole run; peak {:.1} TPS included.", self.peak_included_tps),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inclusion::TxStatus;
+
+    fn scheduled(profile: &str, duration: f64) -> usize {
+        profile.parse::<LoadProfile>().unwrap().schedule(duration).len()
+    }
+
+    #[test]
+    fn schedule_count_matches_the_integrated_rate() {
+        assert!(scheduled("constant:50", 10.0).abs_diff(500) <= 1);
+        // (10 + 200) / 2 × 10 s
+        assert!(scheduled("ramp:10..200", 10.0).abs_diff(1050) <= 1);
+        // 10, 20 and 30 TPS for 10 s each
+        assert!(scheduled("step:10..30:10", 30.0).abs_diff(600) <= 1);
+        // 20 TPS for nine tenths of 10 s, 200 TPS for the rest
+        assert!(scheduled("spike:20..200", 10.0).abs_diff(380) <= 1);
+    }
+
+    #[test]
+    fn schedule_is_ordered_within_the_run() {
+        let sends = LoadProfile::Ramp { from: 5.0, to: 50.0 }.schedule(20.0);
+        assert!(sends.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(sends.iter().all(|&t| (0.0..20.0).contains(&t)));
+    }
+
+    /// Transactions sent on `profile` that land in the next 6 s block until `stops_at`, and never after
+    fn run(profile: &LoadProfile, duration: f64, stops_at: f64) -> LoadCurve {
+        let traces: Vec<(f64, TxTrace)> = profile
+            .schedule(duration)
+            .into_iter()
+            .enumerate()
+            .map(|(i, at)| {
+                let mut trace = TxTrace::new(format!("0x{:x}", i), at);
+                if at < stops_at {
+                    let block = ((at / 6.0).floor() + 1.0) * 6.0;
+                    trace.apply(&TxStatus::InBlock(format!("0x{}", block)), block);
+                }
+                (at, trace)
+            })
+            .collect();
+        let sent: Vec<(f64, Option<&TxTrace>)> = traces.iter().map(|(at, trace)| (*at, Some(trace))).collect();
+        LoadCurve::measure(profile, duration, &sent)
+    }
+
+    #[test]
+    fn knee_is_the_last_rate_kept_up_with() {
+        let profile = LoadProfile::Ramp { from: 1.0, to: 20.0 };
+        let curve = run(&profile, 60.0, 30.0);
+        assert_eq!(curve.block_secs, 6.0);
+        assert_eq!(curve.windows.len(), 10);
+        assert!(curve.saturated);
+        let knee = curve.knee_tps.unwrap();
+        assert_eq!(knee, curve.windows[4].offered_tps);
+        assert!(knee > curve.windows[0].offered_tps);
+        assert!(curve.summary().starts_with("Saturation knee"));
+    }
+
+    #[test]
+    fn no_knee_when_the_chain_keeps_up() {
+        let curve = run(&LoadProfile::Constant { rate: 5.0 }, 60.0, f64::INFINITY);
+        assert!(!curve.saturated);
+        assert!(curve.knee_tps.is_none());
+        assert!(curve.windows.iter().all(|w| w.landed == 1.0));
+    }
+
+    #[test]
+    fn no_knee_when_behind_from_the_start() {
+        let curve = run(&LoadProfile::Constant { rate: 5.0 }, 60.0, 0.0);
+        assert!(curve.saturated);
+        assert!(curve.knee_tps.is_none());
+        assert!(curve.summary().starts_with("Behind from the first window"));
+    }
+}
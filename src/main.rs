@@ -5,21 +5,49 @@
 //!
 //! SPHINCS+ is a NIST-standardized hash-based signature scheme that provides
 //! quantum resistance. This benchmark demonstrates how QuantumHarmony achieves
-//! high TPS despite the ~250ms verification time per signature.
+//! high TPS despite its slow verification compared to classical signatures.
+//!
+//! Other schemes (ML-DSA, Falcon, Ed25519) can be selected with --scheme to
+//! compare against SPHINCS+ on the same machine.
 //!
 //! NETWORK MODE: Use --network to test against real QuantumHarmony validators!
 
+mod ui;
+
 use clap::Parser;
 use colored::*;
-use pqcrypto_sphincsplus::sphincsshake128fsimple::*;
-use pqcrypto_traits::sign::{PublicKey, SecretKey, SignedMessage};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use sha3::{Digest, Sha3_256};
-use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::marker::PhantomData;
 use std::time::{Duration, Instant};
 
+use quantumharmony_benchmark::assertions::{AssertionResult, Assertions, SpeedupAssertion};
+use quantumharmony_benchmark::baseline::{self, BaselineStore};
+use quantumharmony_benchmark::capacity::{CapacityProjection, ScalingModel, ValidatorSpec, VerifyRate};
+use quantumharmony_benchmark::environment::Environment;
+use quantumharmony_benchmark::extrinsic::{self, BlockExtrinsics, ExtrinsicFormat};
+use quantumharmony_benchmark::heads::{self, HeadStats, HeadTimeline};
+use quantumharmony_benchmark::inclusion::{self, InclusionStats, TxTrace};
+use quantumharmony_benchmark::{html, junit, markdown, ws};
+use quantumharmony_benchmark::latency::LatencySummary;
+use quantumharmony_benchmark::load::{LoadCurve, LoadProfile};
+use quantumharmony_benchmark::report::{
+    BenchmarkReport, NetworkMetrics, RealTpsMetrics, RunConfig, SchemeRow, StrategyRow, ValidatorStatus,
+};
+use quantumharmony_benchmark::scalability::{Scalability, ScalingPoint};
+use quantumharmony_benchmark::scenario::{Scenario, Suite};
+use quantumharmony_benchmark::scheme::{MatrixSet, Scheme, SchemeVisitor, SignatureScheme};
+use quantumharmony_benchmark::simulate::{self, Consensus, SimConfig, SimulationMetrics};
+use quantumharmony_benchmark::submit::{self, DirectSubmission, Outcome};
+use quantumharmony_benchmark::strategy::{
+    measure, Chunked, FlatRayon, RayonPool, Sequential, StaticSegments, ThreadPool, Torus, Trials,
+    Verifiable, VerificationStrategy,
+};
+use quantumharmony_benchmark::sweep::SweepSpec;
+use quantumharmony_benchmark::torus::{MeshStats, TorusMesh};
+use ui::{say, sayln};
+
 /// SPHINCS+ TPS Benchmark for QuantumHarmony
 #[derive(Parser, Debug)]
 #[command(name = "sphincs-benchmark")]
@@ -28,25 +56,71 @@ use std::time::{Duration, Instant};
 #[command(about = "Benchmark SPHINCS+ post-quantum signature verification throughput")]
 struct Args {
     /// Number of transactions to generate and verify
-    #[arg(short, long, default_value = "100")]
+    #[arg(short, long, default_value = "100", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
     transactions: usize,
 
-    /// Number of parallel segments (toroidal mesh)
-    #[arg(short, long, default_value = "64")]
+    /// Segment count for the strategy comparison; the default sweep runs powers of two up to it
+    #[arg(short, long, default_value = "64", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
     segments: usize,
 
+    /// Segment counts for the torus sweep: a list (2,4,8), a range with a step (8..64:8),
+    /// powers of two up to N (pow2:512) or core-count multiples (cores:4) [default: pow2:SEGMENTS]
+    #[arg(long)]
+    sweep: Option<SweepSpec>,
+
+    /// Thread counts for a rayon scaling sweep with Amdahl/USL fits, e.g. 1..8 or cores:2
+    /// (one thread is always measured) [default: off]
+    #[arg(long)]
+    thread_sweep: Option<SweepSpec>,
+
+    /// Also run an explicit torus shape (XxYxZ, e.g. 8x8x8) and print its per-segment load
+    #[arg(long)]
+    torus: Option<TorusMesh>,
+
+    /// Worker threads for the fixed-size thread pool strategy [default: all cores]
+    #[arg(long)]
+    pool_threads: Option<usize>,
+
+    /// Measured runs per strategy (reported as mean/median/stddev/95% CI)
+    #[arg(long, default_value = "5")]
+    iterations: usize,
+
+    /// Untimed warmup runs per strategy before measuring
+    #[arg(long, default_value = "1")]
+    warmup: usize,
+
     /// Number of keypairs to generate (reused for signing)
-    #[arg(short, long, default_value = "10")]
+    #[arg(short, long, default_value = "10", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
     keypairs: usize,
 
-    /// Run quick mode (fewer transactions for fast results)
+    /// Signature scheme(s) to benchmark (comma-separated)
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "shake-128f")]
+    scheme: Vec<Scheme>,
+
+    /// Run a set of schemes and print a comparison table [default: sphincs]
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "sphincs")]
+    matrix: Option<MatrixSet>,
+
+    /// Also verify via the attached SignedMessage/open API and report the overhead
     #[arg(long)]
+    both_paths: bool,
+
+    /// Run quick mode (the bundled `quick` scenario: 10 and 25 transactions)
+    #[arg(long, conflicts_with_all = ["full", "scenario"])]
     quick: bool,
 
-    /// Run full benchmark suite
-    #[arg(long)]
+    /// Run full benchmark suite (the bundled `full` scenario: 10 to 500 transactions)
+    #[arg(long, conflicts_with = "scenario")]
     full: bool,
 
+    /// Run the suites of a TOML scenario file (or a bundled scenario: quick, full)
+    #[arg(long, value_name = "FILE")]
+    scenario: Option<String>,
+
+    /// Only run these suites of the scenario (comma-separated)
+    #[arg(long, value_delimiter = ',', requires = "scenario")]
+    suite: Vec<String>,
+
     /// Run REAL network benchmark against live validators
     #[arg(long)]
     network: bool,
@@ -59,9 +133,236 @@ struct Args {
     #[arg(long, default_value = "http://51.79.26.123:8080")]
     faucet: String,
 
+    /// With --real-tps, sign transfers locally (first --scheme) and submit them with
+    /// author_submitExtrinsic to every online validator instead of using the faucet
+    #[arg(long, requires = "real_tps")]
+    direct: bool,
+
+    /// Runtime layout of --direct transfers: call=PALLET:CALL[,signature=VARIANT][,metadata_hash=BOOL]
+    /// [default: call=4:3,signature=3,metadata_hash=true]
+    #[arg(long, value_name = "FORMAT")]
+    extrinsic_format: Option<ExtrinsicFormat>,
+
+    /// Signing key for --direct: a file with the hex public key and hex secret key on two lines
+    /// [default: a fresh, unfunded keypair]
+    #[arg(long, value_name = "FILE")]
+    signer_key: Option<std::path::PathBuf>,
+
+    /// WebSocket connections per validator for --direct
+    #[arg(long, default_value = "4")]
+    submit_connections: usize,
+
+    /// Open-loop load for --direct: constant:RATE, ramp:FROM..TO, step:FROM..TO:STEP or spike:BASE..PEAK
+    /// (transactions per second); replaces --transactions with the profile's schedule
+    #[arg(long, value_name = "PROFILE", requires = "direct")]
+    load: Option<LoadProfile>,
+
+    /// Length of the --load run
+    #[arg(long, default_value = "60", value_name = "SECS")]
+    load_duration: f64,
+
+    /// How long --real-tps follows submitted transactions to finality after submitting
+    #[arg(long, default_value = "60", value_name = "SECS")]
+    confirm_timeout: f64,
+
+    /// Simulate block production for the --validator-spec validators instead of benchmarking
+    #[arg(long, conflicts_with_all = ["network", "real_tps"])]
+    simulate: bool,
+
+    /// Consensus for --simulate; hotstuff is also compared with Aura on the same load
+    #[arg(long, value_enum, default_value = "aura")]
+    sim_consensus: Consensus,
+
+    /// Simulated seconds
+    #[arg(long, default_value = "300", value_name = "SECS")]
+    sim_duration: f64,
+
+    /// Offered load for --simulate [default: the projected capacity]
+    #[arg(long, value_name = "TPS")]
+    sim_load: Option<f64>,
+
+    /// Mean gossip latency for transactions, blocks and votes in --simulate
+    #[arg(long, default_value = "150", value_name = "MS")]
+    sim_gossip_ms: f64,
+
+    /// Block size limit for --simulate
+    #[arg(long, default_value_t = simulate::DEFAULT_BLOCK_BYTES, value_name = "BYTES")]
+    sim_block_bytes: usize,
+
+    /// Mempool capacity for --simulate, in transactions
+    #[arg(long, default_value_t = simulate::DEFAULT_MEMPOOL_LIMIT, value_name = "TXS")]
+    sim_mempool: usize,
+
+    /// Random seed for --simulate arrivals and gossip delays
+    #[arg(long, default_value = "1")]
+    sim_seed: u64,
+
     /// Validator RPC endpoints (comma-separated)
     #[arg(long, default_value = "http://51.79.26.123:9944,http://51.79.26.168:9944,http://209.38.225.4:9944")]
     validators: String,
+
+    /// Validator class for the capacity projection: cores=N[,count=N][,block_time=SECS][,block_weight=SECS]
+    /// (repeatable) [default: this machine, 6 s blocks, 2 s weight]
+    #[arg(long, value_name = "SPEC")]
+    validator_spec: Vec<ValidatorSpec>,
+
+    /// Project --network or --simulate capacity from a stored run (baseline name or JSON report path)
+    #[arg(long, value_name = "BASELINE")]
+    capacity_from: Option<String>,
+
+    /// Result format; anything but table goes to stdout (tables move to stderr) unless --out is set
+    #[arg(long, value_enum)]
+    output: Option<OutputFormat>,
+
+    /// Write machine-readable results to this file (format from --output, else by extension)
+    #[arg(long)]
+    out: Option<std::path::PathBuf>,
+
+    /// Also write a JUnit XML report (one test case per assertion and strategy)
+    #[arg(long, value_name = "FILE")]
+    junit: Option<std::path::PathBuf>,
+
+    /// Also write a Markdown report (environment, result tables, network results)
+    #[arg(long, value_name = "FILE")]
+    markdown: Option<std::path::PathBuf>,
+
+    /// Also write a self-contained HTML report with SVG scaling, latency and load charts
+    #[arg(long, value_name = "FILE")]
+    html: Option<std::path::PathBuf>,
+
+    /// Store this run's results as a named baseline for --compare
+    #[arg(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compare two stored results (baseline names or JSON report paths) instead of benchmarking
+    #[arg(long, num_args = 2, value_names = ["OLD", "NEW"])]
+    compare: Option<Vec<String>>,
+
+    /// Mean TPS drop, in percent, that --compare treats as a regression
+    #[arg(long, default_value = "5.0", value_name = "PCT")]
+    regression_threshold: f64,
+
+    /// Directory holding named baselines
+    #[arg(long, default_value = "target/baselines")]
+    baseline_dir: std::path::PathBuf,
+
+    /// Fail unless every sequential baseline reaches this TPS
+    #[arg(long, value_name = "TPS")]
+    assert_min_sequential_tps: Option<f64>,
+
+    /// Fail unless the torus sweep reaches SPEEDUP at SEGMENTS, e.g. 4.0@64 (repeatable)
+    #[arg(long, value_name = "SPEEDUP@SEGMENTS")]
+    assert_min_speedup: Vec<SpeedupAssertion>,
+
+    /// Fail if any strategy's p99 verify latency exceeds this many milliseconds
+    #[arg(long, value_name = "MS")]
+    assert_max_p99_ms: Option<f64>,
+}
+
+/// Exit code when --compare finds a throughput regression
+const EXIT_REGRESSION: i32 = 3;
+/// Exit code when an --assert-* threshold is not met
+const EXIT_ASSERTION_FAILED: i32 = 4;
+/// Exit code when --compare finds no rows the two reports share
+const EXIT_NOTHING_COMPARED: i32 = 5;
+
+/// How results are emitted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Colored tables only
+    Table,
+    Json,
+    Csv,
+    Markdown,
+    Junit,
+    Html,
+}
+
+impl OutputFormat {
+    /// `--output`, or a guess from the `--out` extension
+    fn resolve(output: Option<OutputFormat>, out: Option<&std::path::Path>) -> OutputFormat {
+        match (output, out) {
+            (Some(format), _) => format,
+            (None, Some(path)) => {
+                let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("").to_ascii_lowercase();
+                match ext.as_str() {
+                    "csv" => OutputFormat::Csv,
+                    "md" | "markdown" => OutputFormat::Markdown,
+                    "xml" => OutputFormat::Junit,
+                    "html" | "htm" => OutputFormat::Html,
+                    _ => OutputFormat::Json,
+                }
+            }
+            (None, None) => OutputFormat::Table,
+        }
+    }
+}
+
+/// Pass/fail table for the --assert-* thresholds
+fn print_assertions(results: &[AssertionResult]) {
+    sayln!("{}", "━━━ Assertions ━━━".blue().bold());
+    sayln!();
+    for result in results {
+        let status = if result.passed { "PASS".green().bold() } else { "FAIL".red().bold() };
+        let actual = match (result.actual, &result.worst_row) {
+            (Some(actual), Some(row)) => format!("worst {:.3} ({})", actual, row),
+            _ => "not measured".to_string(),
+        };
+        sayln!("  {} {:40} {}", status, result.requirement, actual.dimmed());
+    }
+    sayln!();
+    let failed = results.iter().filter(|r| !r.passed).count();
+    if failed == 0 {
+        sayln!("{}", format!("All {} assertion(s) passed", results.len()).green().bold());
+    } else {
+        sayln!("{}", format!("{} of {} assertion(s) failed", failed, results.len()).red().bold());
+    }
+    sayln!();
+}
+
+/// Emit the report and store it as a baseline if asked to
+fn finish_report(report: &BenchmarkReport, args: &Args, format: OutputFormat) {
+    emit_report(report, format, args.out.as_deref());
+    if let Some(path) = &args.junit {
+        emit_report(report, OutputFormat::Junit, Some(path));
+    }
+    if let Some(path) = &args.markdown {
+        emit_report(report, OutputFormat::Markdown, Some(path));
+    }
+    if let Some(path) = &args.html {
+        emit_report(report, OutputFormat::Html, Some(path));
+    }
+    if let Some(name) = &args.save_baseline {
+        match BaselineStore::new(&args.baseline_dir).save(name, report) {
+            Ok(path) => sayln!("{} '{}' → {}", "Baseline saved".dimmed(), name, path.display()),
+            Err(e) => {
+                eprintln!("{}", format!("Failed to save baseline: {}", e).red());
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Write `report` in `format` to `out`, or to stdout
+fn emit_report(report: &BenchmarkReport, format: OutputFormat, out: Option<&std::path::Path>) {
+    let body = match format {
+        OutputFormat::Table => return,
+        OutputFormat::Json => report.to_json() + "\n",
+        OutputFormat::Csv => report.to_csv(),
+        OutputFormat::Markdown => markdown::render(report),
+        OutputFormat::Junit => junit::render(report),
+        OutputFormat::Html => html::render(report),
+    };
+    match out {
+        Some(path) => match std::fs::write(path, body) {
+            Ok(()) => sayln!("{} {}", "Results written to".dimmed(), path.display()),
+            Err(e) => {
+                eprintln!("{}", format!("Failed to write {}: {}", path.display(), e).red());
+                std::process::exit(1);
+            }
+        },
+        None => print!("{}", body),
+    }
 }
 
 // JSON-RPC types for Substrate
@@ -97,21 +398,44 @@ struct SystemHealth {
     is_syncing: bool,
 }
 
-/// A mock transaction with SPHINCS+ signature
-struct SignedTransaction {
+/// How a transaction's signature is carried and checked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum VerifyPath {
+    /// Detached signature checked with `verify`, as a chain runtime does
+    Detached,
+    /// Signed message (signature + message) round-tripped through `open`
+    Attached,
+}
+
+impl std::fmt::Display for VerifyPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyPath::Detached => f.write_str("detached"),
+            VerifyPath::Attached => f.write_str("attached"),
+        }
+    }
+}
+
+/// A mock transaction signed with scheme `S` (SPHINCS+ by default)
+struct SignedTransaction<S: SignatureScheme> {
     payload: Vec<u8>,
+    /// Detached signature, or the full signed message for `VerifyPath::Attached`
     signature: Vec<u8>,
     public_key: Vec<u8>,
     segment_id: u32,
+    path: VerifyPath,
+    _scheme: PhantomData<fn() -> S>,
 }
 
-impl SignedTransaction {
+impl<S: SignatureScheme> SignedTransaction<S> {
     fn new(
         keypair: &(Vec<u8>, Vec<u8>), // (public_key, secret_key)
         to: &[u8],
         amount: u64,
         nonce: u64,
         segment_id: u32,
+        path: VerifyPath,
+        payload_bytes: Option<usize>,
     ) -> Self {
         // Create transaction payload
         let mut payload = Vec::new();
@@ -119,219 +443,895 @@ impl SignedTransaction {
         payload.extend_from_slice(to);
         payload.extend_from_slice(&amount.to_le_bytes());
         payload.extend_from_slice(&nonce.to_le_bytes());
+        if let Some(size) = payload_bytes {
+            // Stand-in for call data: pad (or cut) to the requested size
+            payload.resize(size, 0);
+        }
 
         // Hash the payload for signing
         let mut hasher = Sha3_256::new();
         hasher.update(&payload);
         let hash = hasher.finalize();
 
-        // Sign with SPHINCS+ (this is expensive!)
-        let sk = SecretKey::from_bytes(&keypair.1).expect("Invalid secret key");
-        let signed_msg = sign(&hash, &sk);
-        let signature = signed_msg.as_bytes().to_vec();
+        // Sign (this is expensive for SPHINCS+!)
+        let signature = Self::sign(&hash, &keypair.1, path);
 
         Self {
             payload,
             signature,
             public_key: keypair.0.clone(),
             segment_id,
+            path,
+            _scheme: PhantomData,
+        }
+    }
+
+    /// Sign `message` the way `path` verifies it
+    fn sign(message: &[u8], secret_key: &[u8], path: VerifyPath) -> Vec<u8> {
+        match path {
+            VerifyPath::Detached => S::sign(message, secret_key),
+            VerifyPath::Attached => S::sign_attached(message, secret_key),
         }
     }
 
-    /// Verify SPHINCS+ signature (expensive operation ~250ms)
+    /// Verify the signature (the expensive operation being measured)
     fn verify(&self) -> bool {
         // Reconstruct hash
         let mut hasher = Sha3_256::new();
         hasher.update(&self.payload);
         let hash = hasher.finalize();
 
-        // Parse public key
-        let pk = match PublicKey::from_bytes(&self.public_key) {
-            Ok(pk) => pk,
-            Err(_) => return false,
-        };
+        match self.path {
+            VerifyPath::Detached => S::verify(&hash, &self.signature, &self.public_key),
+            // Open (allocates and copies the whole signed message) and check
+            // that the recovered message matches our hash
+            VerifyPath::Attached => match S::open(&self.signature, &self.public_key) {
+                Some(opened) => opened == hash.as_slice(),
+                None => false,
+            },
+        }
+    }
+}
 
-        // Create signed message for verification
-        let signed_msg = match SignedMessage::from_bytes(&self.signature) {
-            Ok(sm) => sm,
-            Err(_) => return false,
-        };
+impl<S: SignatureScheme> Verifiable for SignedTransaction<S> {
+    fn verify(&self) -> bool {
+        SignedTransaction::verify(self)
+    }
+
+    fn segment_id(&self) -> usize {
+        self.segment_id as usize
+    }
+}
 
-        // Verify and check if the opened message matches our hash
-        match open(&signed_msg, &pk) {
-            Ok(opened) => opened == hash.as_slice(),
-            Err(_) => false,
+/// Generate keypairs for scheme `S`, in parallel or on the calling thread
+fn generate_keypairs<S: SignatureScheme>(count: usize, parallel: bool) -> Vec<(Vec<u8>, Vec<u8>)> {
+    if parallel {
+        (0..count).into_par_iter().map(|_| S::keypair()).collect()
+    } else {
+        (0..count).map(|_| S::keypair()).collect()
+    }
+}
+
+/// Sign `tx_count` transactions round-robin over `keypairs`, in parallel or on the calling thread
+fn sign_transactions<S: SignatureScheme>(
+    tx_count: usize,
+    keypairs: &[(Vec<u8>, Vec<u8>)],
+    path: VerifyPath,
+    payload_bytes: Option<usize>,
+    parallel: bool,
+) -> Vec<SignedTransaction<S>> {
+    let sign_one = |i: usize| {
+        let kp = &keypairs[i % keypairs.len()];
+        let to = vec![0xFFu8; 32];
+        SignedTransaction::new(kp, &to, 1000, i as u64, (i % 512) as u32, path, payload_bytes)
+    };
+
+    if parallel {
+        (0..tx_count).into_par_iter().map(sign_one).collect()
+    } else {
+        (0..tx_count).map(sign_one).collect()
+    }
+}
+
+fn print_header(schemes: &[Scheme]) {
+    sayln!();
+    sayln!("{}", "╔══════════════════════════════════════════════════════════════════╗".cyan());
+    sayln!("{}", "║     QUANTUMHARMONY SPHINCS+ TPS BENCHMARK                        ║".cyan());
+    sayln!("{}", "║     Post-Quantum Signature Verification Performance             ║".cyan());
+    sayln!("{}", "╚══════════════════════════════════════════════════════════════════╝".cyan());
+    sayln!();
+    if let [scheme] = schemes {
+        let info = scheme.info();
+        sayln!("{}", format!("About {}:", info.name).yellow().bold());
+        sayln!("  - Family: {}", info.family);
+        if info.is_post_quantum() {
+            sayln!("  - Post-quantum, NIST Level {}", info.nist_level);
+        } else {
+            sayln!("  - Classical baseline, NOT quantum safe");
+        }
+        sayln!("  - Public key: {} bytes, signature: {} bytes", info.public_key_bytes, info.signature_bytes);
+    } else {
+        sayln!("{}", format!("Schemes ({}, comparison mode):", schemes.len()).yellow().bold());
+        for scheme in schemes {
+            let info = scheme.info();
+            let level = if info.is_post_quantum() {
+                format!("NIST Level {}", info.nist_level)
+            } else {
+                "classical".to_string()
+            };
+            sayln!("  - {} ({}, {}), {}-byte signatures", info.name, info.family, level, info.signature_bytes);
         }
     }
+    sayln!();
+    sayln!("{}", "QuantumHarmony Optimizations:".yellow().bold());
+    sayln!("  - Toroidal mesh parallelization (512 segments = 8×8×8 3D torus)");
+    sayln!("  - Idle segments steal work from their six torus neighbors");
+    sayln!("  - Pre-verification pool moves verification off critical path");
+    sayln!("  - Distributed workload across validator nodes");
+    sayln!();
 }
 
-/// Generate SPHINCS+ keypairs
-fn generate_keypairs(count: usize) -> Vec<(Vec<u8>, Vec<u8>)> {
-    (0..count)
-        .map(|_| {
-            let (pk, sk) = keypair();
-            (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
-        })
-        .collect()
+/// Print the host and build fingerprint recorded in the report
+fn print_environment(env: &Environment) {
+    let build = &env.build;
+    let flags = env.notable_cpu_flags();
+    sayln!("{}", "Environment:".yellow().bold());
+    sayln!("  - CPU: {}", env.cpu_model.as_deref().unwrap_or("unknown"));
+    sayln!(
+        "  - Cores: {} physical / {} logical, flags: {}",
+        env.physical_cores,
+        env.logical_cores,
+        if flags.is_empty() { "-".to_string() } else { flags.join(" ") }
+    );
+    sayln!(
+        "  - Memory: {}, {} {} {}",
+        env.memory_gib().map_or("unknown".to_string(), |gib| format!("{:.1} GiB", gib)),
+        env.os,
+        env.arch,
+        env.kernel.as_deref().unwrap_or("")
+    );
+    sayln!(
+        "  - Build: v{} {} ({}, {}), features: [{}]",
+        build.crate_version,
+        if build.git_commit.is_empty() { "(no git)" } else { &build.git_commit },
+        build.profile,
+        build.rustc_version,
+        build.features.join(", ")
+    );
+    if build.profile != "release" {
+        sayln!("  {}", "Warning: not a release build, numbers will be far below real throughput".red());
+    }
+    sayln!();
 }
 
-/// Sequential verification (baseline)
-fn verify_sequential(transactions: &[SignedTransaction]) -> (usize, Duration) {
-    let start = Instant::now();
-    let mut verified = 0;
+fn print_result(label: &str, trials: &Trials, total: usize, baseline_tps: Option<f64>) {
+    let tps = &trials.tps;
+    let speedup = baseline_tps.map(|b| tps.mean / b);
+
+    say!("  {:20} ", label);
+    say!("{} ", format!("{:>8.0} TPS", tps.mean).green().bold());
+    say!("±{:<6.0} ", tps.ci95);
+    say!("{} ", format!("med {:>6.0} sd {:>6.1} [{:.0}..{:.0}]", tps.median, tps.stddev, tps.min, tps.max).dimmed());
+    say!("[{}/{}] ", trials.last.verified, total);
+    if !tps.outliers.is_empty() {
+        let values: Vec<String> = tps.outlier_values().iter().map(|v| format!("{:.0}", v)).collect();
+        say!("{} ", format!("⚠ outlier run(s): {} TPS", values.join(", ")).red());
+    }
 
-    for tx in transactions {
-        if tx.verify() {
-            verified += 1;
+    if let Some(s) = speedup {
+        if s > 1.0 {
+            sayln!("{}", format!("{:.1}x speedup", s).yellow());
+        } else {
+            sayln!();
         }
+    } else {
+        sayln!("{}", "(baseline)".dimmed());
     }
+}
 
-    (verified, start.elapsed())
+/// Print projected network TPS per scheme row, then the assumptions behind it
+fn print_capacity(projections: &[CapacityProjection]) {
+    let Some(first) = projections.first() else { return };
+    sayln!("{}", "Capacity Projection:".yellow().bold());
+    // Assumptions every projection shares are printed once, at the end
+    let shared: Vec<&String> = first
+        .assumptions
+        .iter()
+        .filter(|a| projections.iter().all(|p| p.assumptions.contains(a)))
+        .collect();
+    for p in projections {
+        let model = match p.model {
+            ScalingModel::Usl { .. } => "USL",
+            ScalingModel::Amdahl { .. } => "Amdahl",
+            ScalingModel::Linear => "linear",
+        };
+        sayln!(
+            "  {} ({}), {} tx of {} bytes: {:.1} TPS per core, {} scaling → {}",
+            p.scheme,
+            p.path,
+            p.tx_count,
+            p.payload_bytes,
+            p.per_core_tps,
+            model,
+            format!("{:.1} TPS network", p.network_tps).green().bold()
+        );
+        for v in &p.validators {
+            sayln!(
+                "    {} × {} cores, {}s blocks, {}s weight: {:.0} verify/s, {:.0} tx/block, {:.1} TPS",
+                v.spec.count,
+                v.spec.cores,
+                v.spec.block_time_secs,
+                v.spec.block_weight_secs,
+                v.verify_tps,
+                v.tx_per_block,
+                v.projected_tps
+            );
+        }
+        for assumption in p.assumptions.iter().filter(|a| !shared.contains(a)) {
+            sayln!("    {}", format!("- {}", assumption).dimmed());
+        }
+    }
+    sayln!("  {}", "Assumptions:".dimmed());
+    for assumption in shared {
+        sayln!("    {}", format!("- {}", assumption).dimmed());
+    }
+    sayln!();
 }
 
-/// Parallel verification with toroidal segmentation
-fn verify_parallel(transactions: &[SignedTransaction], num_segments: usize) -> (usize, Duration) {
-    // Partition transactions by segment
-    let mut segment_txs: Vec<Vec<&SignedTransaction>> = vec![Vec::new(); num_segments];
-    for tx in transactions {
-        let idx = (tx.segment_id as usize) % num_segments;
-        segment_txs[idx].push(tx);
+/// Print the Amdahl and USL fits for a thread-count sweep
+fn print_scalability(fit: &Scalability) {
+    let single = fit.single_thread_tps;
+    sayln!("  {}", format!("Scalability fit (1 thread = {:.0} TPS)", single).yellow());
+    match &fit.amdahl {
+        Some(amdahl) => sayln!(
+            "  {:20} serial fraction {:.1}% → ceiling {} (R² {:.3})",
+            "Amdahl:",
+            amdahl.serial_fraction * 100.0,
+            match (amdahl.max_speedup, amdahl.max_tps) {
+                (Some(speedup), Some(tps)) => format!("{:.1}× / {:.0} TPS", speedup, tps).green().bold(),
+                _ => "none (linear scaling)".green().bold(),
+            },
+            amdahl.r_squared
+        ),
+        None => sayln!("  {:20} {}", "Amdahl:", "needs a point above one thread".dimmed()),
     }
+    match &fit.usl {
+        Some(usl) => sayln!(
+            "  {:20} contention σ {:.4}, coherency κ {:.6} → peak {} (R² {:.3})",
+            "USL:",
+            usl.contention,
+            usl.coherency,
+            match (usl.peak_threads, usl.peak_tps) {
+                (Some(threads), Some(tps)) => format!("{:.0} TPS at {:.0} threads", tps, threads).green().bold(),
+                _ => "none (no coherency cost)".green().bold(),
+            },
+            usl.r_squared
+        ),
+        None => sayln!("  {:20} {}", "USL:", "needs two thread counts above one".dimmed()),
+    }
+}
 
-    let verified = Arc::new(AtomicUsize::new(0));
-    let start = Instant::now();
+/// Print per-segment load, steals and imbalance for a mesh run. With `detail`,
+/// also print every segment's load as one X×Y grid per Z layer.
+fn print_mesh_stats(stats: &MeshStats, detail: bool) {
+    let [x, y, z] = stats.dims;
+    sayln!(
+        "{}",
+        format!(
+            "    └ torus {}×{}×{}: load {}..{} (mean {:.1}), {} steals, imbalance {:.2}x",
+            x,
+            y,
+            z,
+            stats.min_load(),
+            stats.max_load(),
+            stats.mean_load(),
+            stats.total_steals(),
+            stats.imbalance()
+        )
+        .dimmed()
+    );
 
-    // Process segments in parallel using rayon
-    segment_txs.par_iter().for_each(|segment| {
-        let mut count = 0;
-        for tx in segment {
-            if tx.verify() {
-                count += 1;
-            }
+    if !detail {
+        return;
+    }
+    for layer in 0..z {
+        sayln!("      z={} (load/steals)", layer);
+        for row in 0..y {
+            let cells: Vec<String> = (0..x)
+                .map(|col| {
+                    let id = col + row * x + layer * x * y;
+                    format!("{:>4}/{:<3}", stats.load[id], stats.steals[id])
+                })
+                .collect();
+            sayln!("        {}", cells.join(" "));
         }
-        verified.fetch_add(count, Ordering::Relaxed);
-    });
+    }
+}
 
-    (verified.load(Ordering::Relaxed), start.elapsed())
-}
-
-fn print_header() {
-    println!();
-    println!("{}", "╔══════════════════════════════════════════════════════════════════╗".cyan());
-    println!("{}", "║     QUANTUMHARMONY SPHINCS+ TPS BENCHMARK                        ║".cyan());
-    println!("{}", "║     Post-Quantum Signature Verification Performance             ║".cyan());
-    println!("{}", "╚══════════════════════════════════════════════════════════════════╝".cyan());
-    println!();
-    println!("{}", "About SPHINCS+ (SLH-DSA):".yellow().bold());
-    println!("  - NIST-standardized post-quantum hash-based signature scheme");
-    println!("  - Provides quantum resistance against Shor's algorithm");
-    println!("  - Signature size: ~17KB (SHAKE128f-simple variant)");
-    println!("  - Verification time: ~250ms per signature");
-    println!();
-    println!("{}", "QuantumHarmony Optimizations:".yellow().bold());
-    println!("  - Toroidal mesh parallelization (512 segments = 8×8×8 3D torus)");
-    println!("  - Pre-verification pool moves verification off critical path");
-    println!("  - Distributed workload across validator nodes");
-    println!();
-}
-
-fn print_result(label: &str, verified: usize, total: usize, duration: Duration, baseline_tps: Option<f64>) {
-    let tps = verified as f64 / duration.as_secs_f64();
-    let speedup = baseline_tps.map(|b| tps / b);
-
-    print!("  {:20} ", label);
-    print!("{} ", format!("{:>8.0} TPS", tps).green().bold());
-    print!("({:>6.3}s) ", duration.as_secs_f64());
-    print!("[{}/{}] ", verified, total);
+/// Print a keygen/signing throughput row, aligned with `print_result`
+fn print_rate(label: &str, unit: &str, count: usize, duration: Duration, baseline_rate: Option<f64>) {
+    let rate = count as f64 / duration.as_secs_f64();
 
-    if let Some(s) = speedup {
-        if s > 1.0 {
-            println!("{}", format!("{:.1}x speedup", s).yellow());
+    say!("  {:20} ", label);
+    say!("{} ", format!("{:>8.0} {}", rate, unit).green().bold());
+    say!("({:>6.3}s) ", duration.as_secs_f64());
+    say!("[{}] ", count);
+
+    match baseline_rate.map(|b| rate / b) {
+        Some(s) if s > 1.0 => sayln!("{}", format!("{:.1}x speedup", s).yellow()),
+        Some(_) => sayln!(),
+        None => sayln!("{}", "(baseline)".dimmed()),
+    }
+}
+
+/// Strategy comparison table: every strategy at the same parallelism, on the same transactions
+fn print_strategy_comparison(rows: &[(String, Trials)], baseline_tps: f64) {
+    let flat_tps = rows
+        .iter()
+        .find(|(name, _)| name == "Flat rayon")
+        .map(|(_, trials)| trials.tps.mean);
+
+    sayln!(
+        "  {:20} {:>12} {:>8} {:>8} {:>8} {:>9} {:>10}",
+        "Strategy", "Mean TPS", "±95% CI", "Median", "Stddev", "Speedup", "vs flat"
+    );
+    for (name, trials) in rows {
+        let tps = &trials.tps;
+        let vs_flat = flat_tps
+            .map(|f| format!("{:+.1}%", (tps.mean / f - 1.0) * 100.0))
+            .unwrap_or_default();
+        let outliers = if tps.outliers.is_empty() {
+            String::new()
         } else {
-            println!();
-        }
-    } else {
-        println!("{}", "(baseline)".dimmed());
+            format!("⚠ {} outlier(s)", tps.outliers.len())
+        };
+        sayln!(
+            "  {:20} {} {:>8.0} {:>8.0} {:>8.1} {:>8.1}x {:>10} {}",
+            name,
+            format!("{:>8.0} TPS", tps.mean).green().bold(),
+            tps.ci95,
+            tps.median,
+            tps.stddev,
+            tps.mean / baseline_tps,
+            vs_flat,
+            outliers.red(),
+        );
     }
 }
 
-fn run_benchmark(tx_count: usize, segments: usize, keypairs: &[(Vec<u8>, Vec<u8>)]) {
-    println!("{}", format!("━━━ Testing with {} transactions ━━━", tx_count).blue().bold());
-    println!();
+/// Per-signature verify latency for every row, marking segment counts above the core count
+fn print_latency_table(rows: &[(String, Option<usize>, LatencySummary)]) {
+    let cores = num_cpus::get();
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
 
-    // Generate signed transactions
-    print!("  Generating {} SPHINCS+ signed transactions... ", tx_count);
-    std::io::Write::flush(&mut std::io::stdout()).unwrap();
+    sayln!(
+        "  {}",
+        format!("Per-signature verify latency (ms, {} cores)", cores).yellow()
+    );
+    sayln!(
+        "  {:20} {:>7} {:>9} {:>9} {:>9} {:>9} {:>9} {:>9}",
+        "Row", "n", "mean", "p50", "p95", "p99", "max", "p99/p50"
+    );
+    for (label, segments, latency) in rows {
+        let oversubscribed = segments.is_some_and(|s| s > cores);
+        let line = format!(
+            "  {:20} {:>7} {:>9.3} {:>9.3} {:>9.3} {:>9.3} {:>9.3} {:>8.2}x",
+            label,
+            latency.count,
+            ms(latency.mean),
+            ms(latency.p50),
+            ms(latency.p95),
+            ms(latency.p99),
+            ms(latency.max),
+            latency.tail_ratio(),
+        );
+        if oversubscribed {
+            sayln!("{} {}", line, "> cores".dimmed());
+        } else {
+            sayln!("{}", line);
+        }
+    }
+}
 
-    let gen_start = Instant::now();
-    let transactions: Vec<SignedTransaction> = (0..tx_count)
-        .map(|i| {
-            let kp = &keypairs[i % keypairs.len()];
-            let to = vec![0xFFu8; 32];
-            SignedTransaction::new(kp, &to, 1000, i as u64, (i % 512) as u32)
-        })
-        .collect();
-    println!("{} ({:.2}s)", "Done".green(), gen_start.elapsed().as_secs_f64());
-    println!();
+fn run_benchmark<S: SignatureScheme>(
+    config: &LocalBenchmark,
+    tx_count: usize,
+    keypairs: &[(Vec<u8>, Vec<u8>)],
+    keygen: &KeygenRates,
+    path: VerifyPath,
+    payload_bytes: Option<usize>,
+) -> (SchemeRow, Vec<StrategyRow>) {
+    let payload = payload_bytes.map(|b| format!(", {}-byte payloads", b)).unwrap_or_default();
+    sayln!(
+        "{}",
+        format!("━━━ Testing with {} transactions ({} signatures{}) ━━━", tx_count, path, payload)
+            .blue()
+            .bold()
+    );
+    sayln!();
+
+    // Generate signed transactions, once serially and once in parallel
+    sayln!("  {}", format!("Signing {} {} transactions", tx_count, S::NAME).yellow());
+    let sign_start = Instant::now();
+    drop(sign_transactions::<S>(tx_count, keypairs, path, payload_bytes, false));
+    let sign_seq_time = sign_start.elapsed();
+    let sign_seq_rate = tx_count as f64 / sign_seq_time.as_secs_f64();
+    print_rate("Sign (sequential)", "sig/s", tx_count, sign_seq_time, None);
+
+    let sign_start = Instant::now();
+    let transactions = sign_transactions::<S>(tx_count, keypairs, path, payload_bytes, true);
+    let payload_bytes = transactions.first().map_or(0, |tx| tx.payload.len());
+    let sign_par_time = sign_start.elapsed();
+    print_rate("Sign (parallel)", "sig/s", tx_count, sign_par_time, Some(sign_seq_rate));
+    sayln!();
+
+    let LocalBenchmark { suite, sweep, segments, torus, pool_threads, thread_sweep, trials, .. } = *config;
+    sayln!(
+        "  {}",
+        format!(
+            "Verifying ({} runs after {} warmup; mean ±95% CI, median, stddev, range)",
+            trials.iterations, trials.warmup
+        )
+        .yellow()
+    );
+    let run = |strategy: &dyn VerificationStrategy<SignedTransaction<S>>| {
+        measure(strategy, &transactions, trials.warmup, trials.iterations)
+    };
+    let mut results = Vec::new();
+    let mut record = |kind: &str, strategy: String, segments: Option<usize>, threads: Option<usize>, trials: &Trials, baseline_tps: f64| {
+        results.push(StrategyRow {
+            suite: suite.map(str::to_string),
+            scheme: S::ID.to_string(),
+            path: path.to_string(),
+            tx_count,
+            payload_bytes,
+            kind: kind.to_string(),
+            strategy,
+            segments,
+            threads,
+            verified: trials.last.verified,
+            total: tx_count,
+            tps: (&trials.tps).into(),
+            // No baseline throughput (`-t 0`) leaves nothing to speed up
+            speedup: if baseline_tps > 0.0 { trials.tps.mean / baseline_tps } else { 0.0 },
+            latency: (&trials.latency).into(),
+            mesh: trials.last.mesh.as_ref().map(Into::into),
+        });
+    };
 
     // Sequential baseline
-    let (seq_verified, seq_time) = verify_sequential(&transactions);
-    let baseline_tps = seq_verified as f64 / seq_time.as_secs_f64();
-    print_result("Sequential", seq_verified, tx_count, seq_time, None);
+    let baseline = run(&Sequential);
+    let baseline_tps = baseline.tps.mean;
+    print_result("Sequential", &baseline, tx_count, None);
+    record("baseline", "Sequential".to_string(), None, None, &baseline, baseline_tps);
+    let mut latency_rows = vec![("Sequential".to_string(), None, baseline.latency.summary())];
 
     // Parallel with different segment counts
-    for num_seg in [2, 4, 8, 16, 32, 64, 128, 256, 512].iter().filter(|&&s| s <= segments * 8) {
-        let (par_verified, par_time) = verify_parallel(&transactions, *num_seg);
-        print_result(&format!("{} segments", num_seg), par_verified, tx_count, par_time, Some(baseline_tps));
+    let mut best_segments = 1;
+    let mut best_parallel_tps = baseline_tps;
+    for num_seg in sweep {
+        let mesh = TorusMesh::for_segments(*num_seg);
+        let result = run(&Torus { mesh });
+        print_result(&format!("{} segments", num_seg), &result, tx_count, Some(baseline_tps));
+        if let Some(mesh_stats) = &result.last.mesh {
+            print_mesh_stats(mesh_stats, false);
+        }
+        latency_rows.push((format!("{} segments", num_seg), Some(*num_seg), result.latency.summary()));
+        record("sweep", format!("Torus {}", mesh), Some(*num_seg), None, &result, baseline_tps);
+
+        if result.tps.mean > best_parallel_tps {
+            best_segments = *num_seg;
+            best_parallel_tps = result.tps.mean;
+        }
     }
 
-    println!();
+    // Explicit torus shape, with the full per-segment breakdown
+    if let Some(mesh) = torus {
+        let result = run(&Torus { mesh });
+        print_result(&format!("Torus {}", mesh), &result, tx_count, Some(baseline_tps));
+        if let Some(mesh_stats) = &result.last.mesh {
+            print_mesh_stats(mesh_stats, true);
+        }
+        latency_rows.push((format!("Torus {}", mesh), Some(mesh.segments()), result.latency.summary()));
+        record("torus", format!("Torus {}", mesh), Some(mesh.segments()), None, &result, baseline_tps);
+    }
+
+    sayln!();
+
+    // Every strategy at the same parallelism, on the same transaction set
+    sayln!(
+        "  {}",
+        format!("Strategy comparison ({} segments, {} pool threads)", segments, pool_threads).yellow()
+    );
+    let strategies: Vec<Box<dyn VerificationStrategy<SignedTransaction<S>>>> = vec![
+        Box::new(FlatRayon),
+        Box::new(StaticSegments { segments }),
+        Box::new(Chunked { chunks: segments }),
+        Box::new(Torus { mesh: TorusMesh::for_segments(segments) }),
+        Box::new(ThreadPool::new(pool_threads)),
+    ];
+    let rows: Vec<(String, Trials)> = strategies
+        .iter()
+        .map(|strategy| (strategy.name(), run(strategy.as_ref())))
+        .collect();
+    print_strategy_comparison(&rows, baseline_tps);
+    sayln!();
+
+    latency_rows.extend(rows.iter().map(|(name, trials)| (name.clone(), None, trials.latency.summary())));
+    print_latency_table(&latency_rows);
+    sayln!();
+
+    for (name, trials) in &rows {
+        record("strategy", name.clone(), Some(segments), None, trials, baseline_tps);
+    }
+
+    // Same work on a dedicated rayon pool per thread count, for the scalability fits
+    let mut scalability = None;
+    if !thread_sweep.is_empty() {
+        sayln!("  {}", "Thread scaling (flat rayon, one pool per thread count)".yellow());
+        let mut points = Vec::with_capacity(thread_sweep.len());
+        for &threads in thread_sweep {
+            let strategy = RayonPool::new(threads).expect("build rayon thread pool");
+            let result = run(&strategy);
+            let name = VerificationStrategy::<SignedTransaction<S>>::name(&strategy);
+            print_result(&name, &result, tx_count, Some(baseline_tps));
+            record("threads", name, None, Some(threads), &result, baseline_tps);
+            points.push(ScalingPoint { threads, tps: result.tps.mean });
+        }
+        scalability = Scalability::fit(&points);
+        if let Some(fit) = &scalability {
+            print_scalability(fit);
+        }
+        sayln!();
+    }
+
+    let info = S::info();
+    let summary = SchemeRow {
+        suite: suite.map(str::to_string),
+        scheme: info.id.to_string(),
+        scheme_name: info.name.to_string(),
+        family: info.family.to_string(),
+        nist_level: info.nist_level,
+        public_key_bytes: info.public_key_bytes,
+        signature_bytes: info.signature_bytes,
+        path: path.to_string(),
+        tx_count,
+        payload_bytes,
+        keygen_seq_per_sec: keygen.sequential,
+        keygen_par_per_sec: keygen.parallel,
+        sign_seq_per_sec: sign_seq_rate,
+        sign_par_per_sec: tx_count as f64 / sign_par_time.as_secs_f64(),
+        sequential_tps: baseline_tps,
+        best_segments,
+        best_parallel_tps,
+        scalability,
+    };
+    (summary, results)
 }
 
-// ==================== NETWORK BENCHMARK FUNCTIONS ====================
+/// Print one row per (scheme, tx count) so schemes can be compared directly
+fn print_scheme_comparison(summaries: &[SchemeRow]) {
+    sayln!("{}", "━━━ SIGNATURE SCHEME COMPARISON ━━━".blue().bold());
+    sayln!();
+    sayln!(
+        "  {:12} {:>8} {:>5} {:>6} {:>7} {:>6} {:>10} {:>10} {:>10} {:>10} {:>12} {:>8}",
+        "Scheme", "Path", "Level", "PK", "Sig", "Txs", "Keygen/s", "Keygen/s ∥", "Sign/s", "Sign/s ∥", "Verify TPS", "Best"
+    );
+    for s in summaries {
+        let level = if s.nist_level > 0 {
+            s.nist_level.to_string()
+        } else {
+            "-".to_string()
+        };
+        sayln!(
+            "  {:12} {:>8} {:>5} {:>6} {:>7} {:>6} {:>10.1} {:>10.1} {:>10.1} {:>10.1} {:>12.0} {}",
+            s.scheme,
+            s.path,
+            level,
+            s.public_key_bytes,
+            s.signature_bytes,
+            s.tx_count,
+            s.keygen_seq_per_sec,
+            s.keygen_par_per_sec,
+            s.sign_seq_per_sec,
+            s.sign_par_per_sec,
+            s.sequential_tps,
+            format!("{:>8.0} TPS @ {} seg", s.best_parallel_tps, s.best_segments).green(),
+        );
+    }
+    sayln!();
+    sayln!(
+        "{}",
+        "  ∥ marks parallel rates; Verify TPS is sequential; Best is the fastest parallel segment count.".dimmed()
+    );
+    sayln!("{}", "  Level '-' marks classical schemes that are NOT quantum safe.".dimmed());
+    sayln!();
+}
 
-/// Check validator health via RPC
-fn check_validator_health(url: &str) -> Result<SystemHealth, String> {
-    let client = reqwest::blocking::Client::builder()
-        .timeout(Duration::from_secs(10))
-        .build()
-        .map_err(|e| e.to_string())?;
+/// Show how much of the measured cost is the attached `open` API rather than the math
+fn print_path_overhead(detached: &SchemeRow, attached: &SchemeRow) {
+    let overhead = (detached.sequential_tps / attached.sequential_tps - 1.0) * 100.0;
+    sayln!(
+        "  {:20} {} (detached {:.0} TPS vs attached {:.0} TPS)",
+        "Attached API cost:",
+        format!("{:+.1}% verify time", overhead).yellow().bold(),
+        detached.sequential_tps,
+        attached.sequential_tps,
+    );
+    sayln!();
+}
 
-    let request = RpcRequest {
-        jsonrpc: "2.0".to_string(),
-        id: 1,
-        method: "system_health".to_string(),
-        params: vec![],
+/// How many times each strategy is run
+#[derive(Clone, Copy)]
+struct TrialConfig {
+    warmup: usize,
+    iterations: usize,
+}
+
+/// Key generation throughput for one scheme, in keys per second
+struct KeygenRates {
+    sequential: f64,
+    parallel: f64,
+}
+
+/// A scenario suite with command-line fallbacks filled in
+struct SuitePlan {
+    name: Option<String>,
+    description: Option<String>,
+    schemes: Vec<Scheme>,
+    tx_counts: Vec<usize>,
+    sweep: SweepSpec,
+    /// The segment counts `sweep` expands to on this machine
+    sweep_segments: Vec<usize>,
+    /// Thread counts for the scaling sweep, starting at one; empty when off
+    thread_sweep: Vec<usize>,
+    segments: usize,
+    keypairs: usize,
+    payloads: Vec<Option<usize>>,
+    trials: TrialConfig,
+    paths: Vec<VerifyPath>,
+    assertions: Assertions,
+}
+
+impl SuitePlan {
+    fn resolve(suite: &Suite, args: &Args) -> Self {
+        let cli_schemes = match args.matrix {
+            Some(set) => set.schemes(),
+            None => args.scheme.clone(),
+        };
+        let both_paths = suite.both_paths.unwrap_or(args.both_paths);
+        let segments = suite.segments.unwrap_or(args.segments);
+        let sweep = suite
+            .sweep
+            .clone()
+            .or_else(|| args.sweep.clone())
+            .unwrap_or(SweepSpec::PowersOfTwo { max: segments });
+        Self {
+            name: (!suite.name.is_empty()).then(|| suite.name.clone()),
+            description: suite.description.clone(),
+            schemes: suite.schemes().expect("validated on load").unwrap_or(cli_schemes),
+            tx_counts: suite.tx_counts.clone().unwrap_or_else(|| vec![args.transactions]),
+            sweep_segments: sweep.values(num_cpus::get()),
+            sweep,
+            thread_sweep: match suite.thread_sweep.as_ref().or(args.thread_sweep.as_ref()) {
+                Some(spec) => {
+                    let mut threads = spec.values(num_cpus::get());
+                    if !threads.contains(&1) {
+                        threads.insert(0, 1);
+                    }
+                    threads
+                }
+                None => Vec::new(),
+            },
+            segments,
+            keypairs: suite.keypairs.unwrap_or(args.keypairs),
+            payloads: match &suite.payload_bytes {
+                Some(sizes) => sizes.iter().map(|&b| Some(b)).collect(),
+                None => vec![None],
+            },
+            trials: TrialConfig {
+                warmup: suite.warmup.unwrap_or(args.warmup),
+                iterations: suite.iterations.unwrap_or(args.iterations).max(1),
+            },
+            paths: if both_paths {
+                vec![VerifyPath::Detached, VerifyPath::Attached]
+            } else {
+                vec![VerifyPath::Detached]
+            },
+            assertions: suite.assertions.clone(),
+        }
+    }
+}
+
+/// Runs the full local pipeline (keygen, signing, verification) for one scheme
+struct LocalBenchmark<'a> {
+    /// Scenario suite name, recorded on every row
+    suite: Option<&'a str>,
+    tx_counts: &'a [usize],
+    /// Segment counts for the torus sweep
+    sweep: &'a [usize],
+    /// Thread counts for the rayon scaling sweep; empty skips it
+    thread_sweep: &'a [usize],
+    segments: usize,
+    torus: Option<TorusMesh>,
+    pool_threads: usize,
+    trials: TrialConfig,
+    keypairs: usize,
+    /// Payload sizes to test; `None` keeps the natural transaction size
+    payloads: &'a [Option<usize>],
+    paths: &'a [VerifyPath],
+}
+
+impl SchemeVisitor for LocalBenchmark<'_> {
+    type Output = (Vec<SchemeRow>, Vec<StrategyRow>);
+
+    fn visit<S: SignatureScheme>(self) -> Self::Output {
+        // Generate keypairs, once serially and once in parallel
+        sayln!("{}", format!("Generating {} {} keypairs...", self.keypairs, S::NAME).yellow());
+        let kp_start = Instant::now();
+        drop(generate_keypairs::<S>(self.keypairs, false));
+        let kp_seq_time = kp_start.elapsed();
+        let keygen_seq_rate = self.keypairs as f64 / kp_seq_time.as_secs_f64();
+        print_rate("Keygen (sequential)", "key/s", self.keypairs, kp_seq_time, None);
+
+        let kp_start = Instant::now();
+        let keypairs = generate_keypairs::<S>(self.keypairs, true);
+        let kp_par_time = kp_start.elapsed();
+        print_rate("Keygen (parallel)", "key/s", self.keypairs, kp_par_time, Some(keygen_seq_rate));
+        sayln!();
+
+        let keygen = KeygenRates {
+            sequential: keygen_seq_rate,
+            parallel: self.keypairs as f64 / kp_par_time.as_secs_f64(),
+        };
+
+        // Run benchmarks
+        let (mut summaries, mut results) = (Vec::new(), Vec::new());
+        for &tx_count in self.tx_counts {
+            for &payload in self.payloads {
+                let runs: Vec<SchemeRow> = self
+                    .paths
+                    .iter()
+                    .map(|&path| {
+                        let (summary, rows) = run_benchmark::<S>(&self, tx_count, &keypairs, &keygen, path, payload);
+                        results.extend(rows);
+                        summary
+                    })
+                    .collect();
+                if let [detached, attached] = runs.as_slice() {
+                    print_path_overhead(detached, attached);
+                }
+                summaries.extend(runs);
+            }
+        }
+        (summaries, results)
+    }
+}
+
+// ==================== BASELINE COMPARISON ====================
+
+/// Compare two stored reports and print per-row deltas; returns the exit code
+fn run_compare(baseline_dir: &std::path::Path, old: &str, new: &str, threshold: f64) -> i32 {
+    let store = BaselineStore::new(baseline_dir);
+    let (old_report, new_report) = match (store.load(old), store.load(new)) {
+        (Ok(o), Ok(n)) => (o, n),
+        (Err(e), _) | (_, Err(e)) => {
+            eprintln!("{}", format!("Failed to load report: {}", e).red());
+            return 1;
+        }
     };
 
-    let response: RpcResponse = client
-        .post(url)
-        .json(&request)
-        .send()
-        .map_err(|e| format!("Connection failed: {}", e))?
-        .json()
-        .map_err(|e| format!("Parse failed: {}", e))?;
+    sayln!();
+    sayln!("{}", format!("━━━ {} → {} ━━━", old, new).blue().bold());
+    let (old_env, new_env) = (&old_report.environment, &new_report.environment);
+    if old_env.cpu_model != new_env.cpu_model || old_env.logical_cores != new_env.logical_cores {
+        sayln!(
+            "  {}",
+            format!(
+                "Warning: different hosts ({} ×{} vs {} ×{})",
+                old_env.cpu_model.as_deref().unwrap_or("unknown"),
+                old_env.logical_cores,
+                new_env.cpu_model.as_deref().unwrap_or("unknown"),
+                new_env.logical_cores
+            )
+            .red()
+        );
+    }
+    if old_env.build.profile != new_env.build.profile {
+        sayln!(
+            "  {}",
+            format!("Warning: build profiles differ ({} vs {})", old_env.build.profile, new_env.build.profile).red()
+        );
+    }
+    sayln!();
 
-    if let Some(error) = response.error {
-        return Err(error.message);
+    let comparison = baseline::compare(&old_report, &new_report, threshold);
+    sayln!(
+        "  {:12} {:9} {:9} {:20} {:>6} {:>12} {:>12} {:>9}",
+        "Scheme", "Path", "Kind", "Strategy", "Tx", "Old TPS", "New TPS", "Δ"
+    );
+    sayln!("  {}", "─".repeat(98));
+    for row in &comparison.rows {
+        let marker = match row.significant {
+            Some(true) => "*",
+            Some(false) => " ",
+            None => "?",
+        };
+        let delta = match row.delta_percent {
+            Some(delta) => format!("{:+.1}%{}", delta, marker),
+            None => "n/a ".to_string(),
+        };
+        let delta = if row.regressed {
+            delta.red().bold()
+        } else if row.significant == Some(true) && row.delta_percent.is_some_and(|d| d > 0.0) {
+            delta.green()
+        } else {
+            delta.normal()
+        };
+        sayln!(
+            "  {:12} {:9} {:9} {:20} {:>6} {:>12.2} {:>12.2} {:>9}",
+            row.key.scheme, row.key.path, row.key.kind, row.key.strategy, row.key.tx_count, row.old_tps, row.new_tps, delta
+        );
+    }
+    sayln!();
+    sayln!("{}", "  * significant at 95% (Welch's t-test), ? too few runs to tell".dimmed());
+    for key in &comparison.missing {
+        sayln!("  {} {}", "only in old:".dimmed(), key);
     }
+    for key in &comparison.added {
+        sayln!("  {} {}", "only in new:".dimmed(), key);
+    }
+    sayln!();
 
-    let result = response.result.ok_or("No result")?;
-    serde_json::from_value(result).map_err(|e| e.to_string())
+    if !comparison.missing.is_empty() {
+        sayln!(
+            "{}",
+            format!("Warning: {} row(s) of {} have no match in {} and were not checked", comparison.missing.len(), old, new)
+                .red()
+                .bold()
+        );
+    }
+    if comparison.rows.is_empty() {
+        sayln!("{}", format!("Nothing to compare: no row of {} matches a row of {}", old, new).red().bold());
+        return EXIT_NOTHING_COMPARED;
+    }
+    match comparison.regressions() {
+        0 => {
+            sayln!(
+                "{}",
+                format!("No regressions beyond {:.1}% ({} rows compared)", threshold, comparison.rows.len()).green()
+            );
+            0
+        }
+        n => {
+            sayln!("{}", format!("{} row(s) regressed by more than {:.1}%", n, threshold).red().bold());
+            EXIT_REGRESSION
+        }
+    }
 }
 
-/// Get current block number from validator
-fn get_block_number(url: &str) -> Result<u64, String> {
-    let client = reqwest::blocking::Client::builder()
+// ==================== NETWORK BENCHMARK FUNCTIONS ====================
+
+/// Blocking HTTP client for validator RPC calls
+fn rpc_client() -> Result<reqwest::blocking::Client, String> {
+    reqwest::blocking::Client::builder()
         .timeout(Duration::from_secs(10))
         .build()
-        .map_err(|e| e.to_string())?;
+        .map_err(|e| e.to_string())
+}
 
+/// Make one JSON-RPC call and return its result
+fn rpc_call(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    method: &str,
+    params: Vec<serde_json::Value>,
+) -> Result<serde_json::Value, String> {
     let request = RpcRequest {
         jsonrpc: "2.0".to_string(),
         id: 1,
-        method: "chain_getHeader".to_string(),
-        params: vec![],
+        method: method.to_string(),
+        params,
     };
 
     let response: RpcResponse = client
@@ -346,7 +1346,18 @@ fn get_block_number(url: &str) -> Result<u64, String> {
         return Err(error.message);
     }
 
-    let result = response.result.ok_or("No result")?;
+    response.result.ok_or_else(|| "No result".to_string())
+}
+
+/// Check validator health via RPC
+fn check_validator_health(url: &str) -> Result<SystemHealth, String> {
+    let result = rpc_call(&rpc_client()?, url, "system_health", vec![])?;
+    serde_json::from_value(result).map_err(|e| e.to_string())
+}
+
+/// Get current block number from validator
+fn get_block_number(url: &str) -> Result<u64, String> {
+    let result = rpc_call(&rpc_client()?, url, "chain_getHeader", vec![])?;
     let number_hex = result
         .get("number")
         .and_then(|n| n.as_str())
@@ -359,90 +1370,189 @@ fn get_block_number(url: &str) -> Result<u64, String> {
     Ok(number)
 }
 
+/// Fetch block `number` and count its extrinsics
+fn get_block_extrinsics(client: &reqwest::blocking::Client, url: &str, number: u64) -> Result<BlockExtrinsics, String> {
+    let hash = rpc_call(client, url, "chain_getBlockHash", vec![number.into()])?
+        .as_str()
+        .ok_or_else(|| format!("No hash for block #{}", number))?
+        .to_string();
+    let block = rpc_call(client, url, "chain_getBlock", vec![hash.clone().into()])?;
+    let extrinsics: Vec<String> = block
+        .pointer("/block/extrinsics")
+        .and_then(|e| serde_json::from_value(e.clone()).ok())
+        .ok_or_else(|| format!("No extrinsics in block #{}", number))?;
+    BlockExtrinsics::count(number, hash, &extrinsics).map_err(|e| format!("block #{}: {}", number, e))
+}
+
+/// Run `future` to completion on a single-threaded tokio runtime
+fn block_on<F: std::future::Future>(future: F) -> Result<F::Output, String> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?
+        .block_on(future))
+}
+
+/// Record new and finalized heads on `url` for `duration`, over WebSocket
+fn watch_heads(url: &str, duration: Duration) -> Result<HeadTimeline, String> {
+    block_on(heads::watch(url, duration))?
+}
+
+fn print_head_stats(heads: &HeadStats) {
+    let secs = |ms: f64| ms / 1000.0;
+    sayln!("{}", "Block Timeline:".yellow().bold());
+    sayln!(
+        "  {:25} {} new, {} finalized, {} reorgs",
+        "Heads:",
+        heads.new_heads,
+        heads.finalized_heads,
+        heads.reorgs
+    );
+    let block_time = &heads.block_time;
+    if block_time.count > 0 {
+        sayln!(
+            "  {:25} mean {:.2}s, p50 {:.2}s, p95 {:.2}s, max {:.2}s",
+            "Block time:",
+            secs(block_time.mean_ms),
+            secs(block_time.p50_ms),
+            secs(block_time.p95_ms),
+            secs(block_time.max_ms)
+        );
+    }
+    let until = match heads.longest_gap_until {
+        Some(number) => format!("#{} → #{}", heads.longest_gap_after, number),
+        None => format!("after #{}, still open at the end", heads.longest_gap_after),
+    };
+    sayln!("  {:25} {:.2}s ({})", "Longest gap:", heads.longest_gap_secs, until);
+    let stalls = format!("{} over 2× the median block time", heads.stalls);
+    sayln!("  {:25} {}", "Stalls:", if heads.stalls > 0 { stalls.red() } else { stalls.normal() });
+    let lag = &heads.finality_lag;
+    if lag.count > 0 {
+        sayln!(
+            "  {:25} p50 {:.2}s, p95 {:.2}s, max {:.2}s ({:.1} blocks behind on average, {} at most)",
+            "Finality lag:",
+            secs(lag.p50_ms),
+            secs(lag.p95_ms),
+            secs(lag.max_ms),
+            heads.finality_lag_blocks_mean,
+            heads.finality_lag_blocks_max
+        );
+    } else {
+        sayln!("  {:25} {}", "Finality lag:", "no block seen both as new and finalized".dimmed());
+    }
+    sayln!();
+}
+
+/// Fetch and count every block after `start` up to `end`
+fn count_extrinsics(url: &str, start: u64, end: u64) -> Result<Vec<BlockExtrinsics>, String> {
+    let client = rpc_client()?;
+    ((start + 1)..=end).map(|number| get_block_extrinsics(&client, url, number)).collect()
+}
+
+/// Display name for the known QuantumHarmony testnet validators
+fn validator_name(url: &str) -> &'static str {
+    if url.contains("51.79.26.123") {
+        "Alice"
+    } else if url.contains("51.79.26.168") {
+        "Bob"
+    } else if url.contains("209.38.225.4") {
+        "Charlie"
+    } else {
+        "Validator"
+    }
+}
+
+fn validator_status(url: &str, health: &Result<SystemHealth, String>) -> ValidatorStatus {
+    ValidatorStatus {
+        url: url.to_string(),
+        name: validator_name(url).to_string(),
+        online: matches!(health, Ok(h) if !h.is_syncing),
+        peers: health.as_ref().ok().map(|h| h.peers),
+        error: health.as_ref().err().cloned(),
+    }
+}
+
 /// Run network benchmark against live validators
-fn run_network_benchmark(validators: &[String], duration_secs: u64) {
-    println!();
-    println!(
+fn run_network_benchmark(
+    validators: &[String],
+    duration_secs: u64,
+    capacity: Option<&CapacityProjection>,
+) -> Option<NetworkMetrics> {
+    sayln!();
+    sayln!(
         "{}",
         "╔══════════════════════════════════════════════════════════════════╗".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "║     LIVE NETWORK TPS BENCHMARK                                   ║".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "║     Testing REAL validator performance                           ║".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "╚══════════════════════════════════════════════════════════════════╝".cyan()
     );
-    println!();
+    sayln!();
 
     // Check all validators
-    println!("{}", "Checking validator connectivity...".yellow());
+    sayln!("{}", "Checking validator connectivity...".yellow());
     let mut online_validators: Vec<(String, String)> = Vec::new();
+    let mut statuses = Vec::new();
 
     for url in validators {
-        print!("  {} ... ", url);
-        std::io::Write::flush(&mut std::io::stdout()).unwrap();
+        say!("  {} ... ", url);
+        ui::flush();
 
-        match check_validator_health(url) {
+        let health = check_validator_health(url);
+        statuses.push(validator_status(url, &health));
+        match health {
             Ok(health) => {
                 if health.is_syncing {
-                    println!("{}", "SYNCING (skip)".yellow());
+                    sayln!("{}", "SYNCING (skip)".yellow());
                 } else {
-                    println!(
+                    sayln!(
                         "{} ({} peers)",
                         "ONLINE".green().bold(),
                         health.peers
                     );
-                    // Extract name from URL for display
-                    let name = if url.contains("51.79.26.123") {
-                        "Alice"
-                    } else if url.contains("51.79.26.168") {
-                        "Bob"
-                    } else if url.contains("209.38.225.4") {
-                        "Charlie"
-                    } else {
-                        "Validator"
-                    };
-                    online_validators.push((url.clone(), name.to_string()));
+                    online_validators.push((url.clone(), validator_name(url).to_string()));
                 }
             }
             Err(e) => {
-                println!("{} ({})", "OFFLINE".red(), e);
+                sayln!("{} ({})", "OFFLINE".red(), e);
             }
         }
     }
 
     if online_validators.is_empty() {
-        println!();
-        println!(
+        sayln!();
+        sayln!(
             "{}",
             "ERROR: No validators online! Cannot run network benchmark.".red()
         );
-        println!("Please ensure the QuantumHarmony network is running.");
-        return;
+        sayln!("Please ensure the QuantumHarmony network is running.");
+        return None;
     }
 
-    println!();
-    println!(
+    sayln!();
+    sayln!(
         "{}: {} validators online",
         "Network Status".yellow().bold(),
         online_validators.len()
     );
-    println!();
+    sayln!();
 
     // Monitor block production for the specified duration
-    println!(
+    sayln!(
         "{}",
         format!("Monitoring block production for {} seconds...", duration_secs)
             .blue()
             .bold()
     );
-    println!();
+    sayln!();
 
     let primary_url = &online_validators[0].0;
 
@@ -450,27 +1560,36 @@ fn run_network_benchmark(validators: &[String], duration_secs: u64) {
     let start_block = match get_block_number(primary_url) {
         Ok(b) => b,
         Err(e) => {
-            println!("{}", format!("Failed to get block number: {}", e).red());
-            return;
+            sayln!("{}", format!("Failed to get block number: {}", e).red());
+            return None;
         }
     };
 
-    println!(
+    sayln!(
         "  Starting block: {}",
         format!("#{}", start_block).green()
     );
 
     let start_time = Instant::now();
 
-    // Wait for the benchmark duration
-    std::thread::sleep(Duration::from_secs(duration_secs));
+    // Follow every head for the benchmark duration; sampling the two ends is the fallback
+    sayln!("  {}", format!("Subscribing to new and finalized heads on {}", ws::ws_url(primary_url)).dimmed());
+    let timeline = match watch_heads(primary_url, Duration::from_secs(duration_secs)) {
+        Ok(timeline) => Some(timeline),
+        Err(e) => {
+            sayln!("  {}", format!("Head subscription failed ({}), sampling block numbers only", e).yellow());
+            std::thread::sleep(Duration::from_secs(duration_secs).saturating_sub(start_time.elapsed()));
+            None
+        }
+    };
+    let heads = timeline.as_ref().and_then(HeadTimeline::stats);
 
     // Get ending block
     let end_block = match get_block_number(primary_url) {
         Ok(b) => b,
         Err(e) => {
-            println!("{}", format!("Failed to get block number: {}", e).red());
-            return;
+            sayln!("{}", format!("Failed to get block number: {}", e).red());
+            return None;
         }
     };
 
@@ -482,71 +1601,123 @@ fn run_network_benchmark(validators: &[String], duration_secs: u64) {
         0.0
     };
 
-    println!(
+    sayln!(
         "  Ending block:   {}",
         format!("#{}", end_block).green()
     );
-    println!();
-
-    // Calculate TPS estimates
-    // SPHINCS+ verification time is ~250ms per signature
-    // With 3 validators and 8 cores each = 24 parallel verifiers
-    // At 4 TPS per core = ~96 TPS theoretical
-    // With toroidal mesh optimization, we can achieve higher throughput
+    sayln!();
 
     let blocks_per_sec = blocks_produced as f64 / elapsed.as_secs_f64();
 
-    // Each block can contain multiple transactions
-    // Estimate based on block production rate and validator capacity
-    let tx_per_block_estimate = 100; // Conservative estimate
+    // Count what the observed blocks actually contain
+    if blocks_produced > 0 {
+        sayln!(
+            "{}",
+            format!("Fetching blocks #{}..#{} to count extrinsics...", start_block + 1, end_block).yellow()
+        );
+    }
+    let blocks = match count_extrinsics(primary_url, start_block, end_block) {
+        Ok(blocks) => Some(blocks),
+        Err(e) => {
+            sayln!("{}", format!("Could not count extrinsics: {}", e).red());
+            None
+        }
+    };
+    let transactions: u64 = blocks.iter().flatten().map(|b| b.transactions as u64).sum();
+    let inherents: u64 = blocks.iter().flatten().map(|b| b.inherents as u64).sum();
+    let measured_tps = blocks.as_ref().map(|_| transactions as f64 / elapsed.as_secs_f64());
+
+    // Not a measurement: assumes every block carries a fixed number of transactions
+    let tx_per_block_estimate = 100;
     let network_tps = blocks_per_sec * tx_per_block_estimate as f64;
 
-    // Calculate theoretical maximum based on SPHINCS+ verification
-    let validator_count = online_validators.len();
-    let cores_per_validator = num_cpus::get(); // Use local cores as estimate
-    let tps_per_core = 4.0; // ~250ms per verification = 4 TPS
-    let theoretical_max = (validator_count * cores_per_validator) as f64 * tps_per_core;
 
-    println!("{}", "━━━ NETWORK TPS RESULTS ━━━".blue().bold());
-    println!();
-    println!(
+    sayln!("{}", "━━━ NETWORK TPS RESULTS ━━━".blue().bold());
+    sayln!();
+    sayln!(
         "  {:25} {}",
         "Blocks produced:",
         format!("{}", blocks_produced).green().bold()
     );
-    println!(
+    sayln!(
         "  {:25} {}",
         "Time elapsed:",
         format!("{:.2}s", elapsed.as_secs_f64()).white()
     );
-    println!(
+    sayln!(
         "  {:25} {}",
         "Block time:",
         format!("{:.2}s", block_time).white()
     );
-    println!(
+    sayln!(
         "  {:25} {}",
         "Blocks/second:",
         format!("{:.2}", blocks_per_sec).yellow()
     );
-    println!();
-    println!(
-        "  {:25} {}",
-        "Estimated Network TPS:",
-        format!("{:.0} TPS", network_tps).green().bold()
-    );
-    println!(
-        "  {:25} {}",
-        "Theoretical Maximum:",
-        format!("{:.0} TPS", theoretical_max).yellow()
+    sayln!();
+    match measured_tps {
+        Some(tps) => {
+            sayln!(
+                "  {:25} {} ({} transactions in {} blocks)",
+                "Measured TPS:",
+                format!("{:.2} TPS", tps).green().bold(),
+                transactions,
+                blocks_produced
+            );
+            sayln!(
+                "  {:25} {} ({:.1} per block, not counted as transactions)",
+                "Inherents:",
+                inherents,
+                inherents as f64 / blocks_produced.max(1) as f64
+            );
+        }
+        None => sayln!("  {:25} {}", "Measured TPS:", "not measured (blocks could not be fetched)".dimmed()),
+    }
+    sayln!(
+        "  {:25} {} (assumes {} tx/block)",
+        "Estimated TPS:",
+        format!("{:.0} TPS", network_tps).white(),
+        tx_per_block_estimate
     );
-    println!();
+    match capacity {
+        Some(c) => sayln!(
+            "  {:25} {} ({} {}, see projection below)",
+            "Projected capacity:",
+            format!("{:.0} TPS", c.network_tps).yellow(),
+            c.scheme,
+            c.path
+        ),
+        None => sayln!(
+            "  {:25} {}",
+            "Projected capacity:",
+            "not projected (pass --capacity-from and --validator-spec)".dimmed()
+        ),
+    }
+    sayln!();
+
+    if let Some(heads) = &heads {
+        print_head_stats(heads);
+    }
+
+    if let Some(blocks) = blocks.as_ref().filter(|b| !b.is_empty()) {
+        sayln!("{}", "Per-Block Extrinsics:".yellow().bold());
+        for block in blocks {
+            sayln!(
+                "  #{:<10} {:>6} tx {:>4} inherents {:>10} bytes",
+                block.number,
+                block.transactions,
+                block.inherents,
+                block.bytes
+            );
+        }
+        sayln!();
+    }
 
     // Show per-validator stats
-    println!("{}", "Per-Validator Performance:".yellow().bold());
+    sayln!("{}", "Per-Validator Performance:".yellow().bold());
     for (url, name) in &online_validators {
         if let Ok(health) = check_validator_health(url) {
-            println!(
+            sayln!(
                 "  {} ({} peers): {}",
                 name.cyan(),
                 health.peers,
@@ -554,22 +1725,358 @@ fn run_network_benchmark(validators: &[String], duration_secs: u64) {
             );
         }
     }
-    println!();
+    sayln!();
 
-    println!("{}", "Note:".dimmed());
-    println!(
+    sayln!("{}", "Note:".dimmed());
+    sayln!(
         "{}",
         "  - Network TPS depends on transaction volume and block capacity".dimmed()
     );
-    println!(
+    sayln!(
         "{}",
-        "  - SPHINCS+ signatures take ~250ms to verify".dimmed()
+        "  - Measured TPS counts signed extrinsics in the observed blocks; inherents are excluded".dimmed()
     );
-    println!(
+    sayln!(
         "{}",
-        "  - Higher TPS achieved through parallel verification".dimmed()
+        format!("  - Estimated TPS assumes {} transactions per block; it is not measured", tx_per_block_estimate).dimmed()
+    );
+    sayln!();
+
+    Some(NetworkMetrics {
+        validators: statuses,
+        start_block,
+        end_block,
+        blocks_produced,
+        elapsed_secs: elapsed.as_secs_f64(),
+        block_time_secs: block_time,
+        blocks_per_sec,
+        estimated_tps: network_tps,
+        measured_tps,
+        transactions,
+        inherents,
+        blocks: blocks.unwrap_or_default(),
+        heads,
+        timeline,
+        // Projected from measured verify rates and --validator-spec, never from local cores
+        theoretical_max_tps: capacity.map_or(0.0, |c| c.network_tps),
+    })
+}
+
+// ==================== NETWORK SIMULATION ====================
+
+/// What the simulated network pays per transaction and per consensus signature
+struct SchemeCosts {
+    rate: VerifyRate,
+    /// Encoded size of one transaction
+    tx_bytes: usize,
+    signature_bytes: usize,
+    /// Seconds to sign one message on one core
+    sign_secs: f64,
+}
+
+/// Measures sign time and one-thread and all-core verify throughput to calibrate `--simulate`
+struct Calibration {
+    tx_count: usize,
+    keypairs: usize,
+    trials: TrialConfig,
+}
+
+impl SchemeVisitor for Calibration {
+    type Output = SchemeCosts;
+
+    fn visit<S: SignatureScheme>(self) -> Self::Output {
+        sayln!(
+            "{}",
+            format!("Calibrating {} sign and verify rates on {} transactions...", S::NAME, self.tx_count).yellow()
+        );
+        let keypairs = generate_keypairs::<S>(self.keypairs, true);
+        let sign_start = Instant::now();
+        let transactions = sign_transactions::<S>(self.tx_count, &keypairs, VerifyPath::Detached, None, false);
+        let sign_time = sign_start.elapsed();
+        print_rate("Sign (sequential)", "sig/s", self.tx_count, sign_time, None);
+
+        let mut points: Vec<ScalingPoint> = Vec::new();
+        let cores = num_cpus::get();
+        for threads in if cores > 1 { vec![1, cores] } else { vec![1] } {
+            let strategy = RayonPool::new(threads).expect("build rayon thread pool");
+            let trials = measure(&strategy, &transactions, self.trials.warmup, self.trials.iterations);
+            let name = VerificationStrategy::<SignedTransaction<S>>::name(&strategy);
+            print_result(&name, &trials, self.tx_count, points.first().map(|p| p.tps));
+            points.push(ScalingPoint { threads, tps: trials.tps.mean });
+        }
+        sayln!();
+
+        let tx = &transactions[0];
+        let rate = VerifyRate {
+            scheme: S::ID.to_string(),
+            path: VerifyPath::Detached.to_string(),
+            tx_count: self.tx_count,
+            payload_bytes: tx.payload.len(),
+            sequential_tps: points[0].tps,
+            scalability: Scalability::fit(&points),
+        };
+        SchemeCosts {
+            rate,
+            tx_bytes: tx.payload.len() + tx.signature.len() + tx.public_key.len(),
+            signature_bytes: S::SIGNATURE_BYTES,
+            sign_secs: sign_time.as_secs_f64() / self.tx_count as f64,
+        }
+    }
+}
+
+/// Costs to simulate with: a stored run, or a short local measurement
+fn calibrate(args: &Args) -> Result<SchemeCosts, String> {
+    if let Some(name) = &args.capacity_from {
+        let measured = BaselineStore::new(&args.baseline_dir).load(name)?;
+        let row = measured.schemes.first().ok_or_else(|| format!("{} has no scheme results", name))?;
+        sayln!("{}", format!("Calibrated from {}: {} ({})", name, row.scheme, row.path).yellow());
+        return Ok(SchemeCosts {
+            rate: row.into(),
+            tx_bytes: row.signature_bytes + row.public_key_bytes + row.payload_bytes,
+            signature_bytes: row.signature_bytes,
+            sign_secs: if row.sign_seq_per_sec > 0.0 { 1.0 / row.sign_seq_per_sec } else { 0.0 },
+        });
+    }
+    let scheme = *args.scheme.first().ok_or("no --scheme to calibrate")?;
+    Ok(scheme.visit(Calibration {
+        tx_count: args.transactions,
+        keypairs: args.keypairs,
+        trials: TrialConfig { warmup: args.warmup, iterations: args.iterations.max(1) },
+    }))
+}
+
+/// Simulate the projected validator set, printing results like `--network`
+///
+/// Returns the `--sim-consensus` run and, for HotStuff, the same load under Aura.
+fn run_simulation(
+    args: &Args,
+    projection: &CapacityProjection,
+    costs: &SchemeCosts,
+) -> Result<(NetworkMetrics, SimulationMetrics, Option<SimulationMetrics>), String> {
+    let specs: Vec<&ValidatorSpec> = projection.validators.iter().map(|v| &v.spec).collect();
+    let first = specs.first().ok_or("no validators to simulate")?;
+    if specs.iter().any(|s| s.block_time_secs != first.block_time_secs || s.block_weight_secs != first.block_weight_secs) {
+        return Err("the simulated network has one block time: every --validator-spec needs the same block_time and block_weight".to_string());
+    }
+    if !(args.sim_duration > 0.0 && args.sim_duration.is_finite()) {
+        return Err("--sim-duration must be a positive number of seconds".to_string());
+    }
+    if !(args.sim_gossip_ms >= 0.0 && args.sim_gossip_ms.is_finite()) {
+        return Err("--sim-gossip-ms must be a non-negative number of milliseconds".to_string());
+    }
+    if args.sim_load.is_some_and(|load| !(load >= 0.0 && load.is_finite())) {
+        return Err("--sim-load must be a non-negative number of transactions per second".to_string());
+    }
+    let config = SimConfig {
+        consensus: args.sim_consensus,
+        verify_tps: projection
+            .validators
+            .iter()
+            .flat_map(|v| std::iter::repeat_n(v.verify_tps, v.spec.count))
+            .collect(),
+        sign_secs: costs.sign_secs,
+        signature_bytes: costs.signature_bytes,
+        block_time_secs: first.block_time_secs,
+        block_weight_secs: first.block_weight_secs,
+        max_block_bytes: args.sim_block_bytes,
+        tx_bytes: costs.tx_bytes,
+        gossip_ms: args.sim_gossip_ms,
+        mempool_limit: args.sim_mempool,
+        offered_tps: args.sim_load.unwrap_or(projection.network_tps),
+        duration_secs: args.sim_duration,
+        seed: args.sim_seed,
+    };
+
+    sayln!();
+    sayln!("{}", "╔══════════════════════════════════════════════════════════════════╗".cyan());
+    sayln!("{}", "║     SIMULATED NETWORK TPS BENCHMARK                              ║".cyan());
+    sayln!("{}", format!("║     {:<61}║", format!("{} consensus, discrete-event model", config.consensus)).cyan());
+    sayln!("{}", "╚══════════════════════════════════════════════════════════════════╝".cyan());
+    sayln!();
+    sayln!(
+        "  {:25} {} ({} tx of {} bytes)",
+        "Validators:",
+        config.verify_tps.len(),
+        projection.scheme,
+        config.tx_bytes
     );
-    println!();
+    sayln!(
+        "  {:25} {}s blocks, {}s weight, {} byte limit",
+        "Blocks:",
+        config.block_time_secs,
+        config.block_weight_secs,
+        config.max_block_bytes
+    );
+    sayln!(
+        "  {:25} {:.1} TPS offered for {}s, {} ms gossip, mempool {}",
+        "Load:",
+        config.offered_tps,
+        config.duration_secs,
+        config.gossip_ms,
+        config.mempool_limit
+    );
+    sayln!();
+
+    let sim = simulate::simulate(&config);
+    let blocks_per_sec = sim.blocks as f64 / sim.duration_secs;
+    let block_time = if sim.blocks > 0 { sim.duration_secs / sim.blocks as f64 } else { 0.0 };
+
+    sayln!("{}", "━━━ NETWORK TPS RESULTS ━━━".blue().bold());
+    sayln!();
+    sayln!("  {:25} {}", "Blocks produced:", format!("{}", sim.blocks).green().bold());
+    sayln!("  {:25} {}", "Time elapsed:", format!("{:.2}s (simulated)", sim.duration_secs).white());
+    sayln!("  {:25} {}", "Block time:", format!("{:.2}s", block_time).white());
+    sayln!("  {:25} {}", "Blocks/second:", format!("{:.2}", blocks_per_sec).yellow());
+    sayln!();
+    sayln!("  {:25} {}", "Simulated TPS:", format!("{:.1} TPS", sim.simulated_tps).green().bold());
+    sayln!("  {:25} {}", "Projected capacity:", format!("{:.1} TPS", projection.network_tps).yellow());
+    sayln!(
+        "  {:25} {} submitted, {} included, {} dropped, {} pending",
+        "Transactions:",
+        sim.submitted,
+        sim.included,
+        sim.dropped,
+        sim.pending
+    );
+    sayln!("  {:25} {} full, {} orphaned", "Blocks:", sim.full_blocks, sim.orphaned_blocks);
+    let latency = &sim.inclusion_latency;
+    sayln!(
+        "  {:25} p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms, max {:.0} ms",
+        "Inclusion latency:",
+        latency.p50_ms,
+        latency.p95_ms,
+        latency.p99_ms,
+        latency.max_ms
+    );
+    let latency = &sim.finality_latency;
+    sayln!(
+        "  {:25} p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms, max {:.0} ms ({} transactions final)",
+        "Finality latency:",
+        latency.p50_ms,
+        latency.p95_ms,
+        latency.p99_ms,
+        latency.max_ms,
+        sim.finalized
+    );
+    sayln!();
+    print_consensus_load(&sim);
+
+    sayln!("{}", "Mempool backlog:".yellow().bold());
+    let step = (sim.backlog.len() / 10).max(1);
+    for sample in sim.backlog.iter().step_by(step) {
+        sayln!(
+            "  {:>8.1}s  {:>8} pending  {:>10} included",
+            sample.time_secs,
+            sample.pending,
+            sample.included
+        );
+    }
+    sayln!();
+
+    let peers = sim.validators.saturating_sub(1) as u32;
+    let network = NetworkMetrics {
+        validators: (0..sim.validators)
+            .map(|i| ValidatorStatus {
+                url: format!("sim://validator-{}", i),
+                name: format!("sim-{}", i),
+                online: true,
+                peers: Some(peers),
+                error: None,
+            })
+            .collect(),
+        start_block: 0,
+        end_block: sim.blocks,
+        blocks_produced: sim.blocks,
+        elapsed_secs: sim.duration_secs,
+        block_time_secs: block_time,
+        blocks_per_sec,
+        estimated_tps: sim.simulated_tps,
+        measured_tps: None,
+        transactions: sim.included,
+        inherents: 0,
+        blocks: Vec::new(),
+        heads: None,
+        timeline: None,
+        theoretical_max_tps: projection.network_tps,
+    };
+
+    let aura = match config.consensus {
+        Consensus::Aura => None,
+        Consensus::Hotstuff => {
+            let aura = simulate::simulate(&SimConfig { consensus: Consensus::Aura, ..config });
+            print_consensus_comparison(&[&aura, &sim]);
+            Some(aura)
+        }
+    };
+    Ok((network, sim, aura))
+}
+
+fn print_consensus_load(sim: &SimulationMetrics) {
+    sayln!("{}", "━━━ CONSENSUS LOAD ━━━".blue().bold());
+    sayln!();
+    match sim.consensus {
+        Consensus::Aura => sayln!("  {:25} Aura, one seal per block", "Consensus:"),
+        Consensus::Hotstuff => sayln!(
+            "  {:25} HotStuff, quorum {} of {} ({} byte certificates)",
+            "Consensus:",
+            sim.quorum,
+            sim.validators,
+            sim.quorum * sim.signature_bytes
+        ),
+    }
+    sayln!(
+        "  {:25} {:.0} per block, {:.2} ms and {} bytes each",
+        "Signatures:",
+        sim.signatures_per_block(),
+        sim.sign_ms,
+        sim.signature_bytes
+    );
+    let produced = (sim.blocks + sim.orphaned_blocks).max(1) as f64;
+    sayln!(
+        "  {:25} {:.0} consensus + {:.0} transaction per block, network-wide",
+        "Verifications:",
+        sim.verifications_per_block(),
+        sim.tx_verifications as f64 / produced
+    );
+    let (min, mean, max) = sim.utilization();
+    sayln!(
+        "  {:25} {:.1}% min, {:.1}% mean, {:.1}% max busy verifying and signing",
+        "Validator load:",
+        min * 100.0,
+        mean * 100.0,
+        max * 100.0
+    );
+    if let Some((i, load)) = sim.busiest() {
+        sayln!(
+            "  {:25} sim-{}: {} transaction + {} consensus verifications, {} signatures",
+            "Busiest validator:",
+            i,
+            load.tx_verifications,
+            load.consensus_verifications,
+            load.signatures
+        );
+    }
+    sayln!();
+}
+
+/// Side-by-side summary of runs of the same load under different consensus
+fn print_consensus_comparison(runs: &[&SimulationMetrics]) {
+    let names: Vec<String> = runs.iter().map(|r| r.consensus.to_string()).collect();
+    sayln!("{}", format!("━━━ {} ━━━", names.join(" VS ").to_uppercase()).blue().bold());
+    sayln!();
+    say!("  {:25}", "");
+    for name in &names {
+        say!(" {:>14}", name);
+    }
+    sayln!();
+    for (label, values) in simulate::compare(runs) {
+        say!("  {:25}", format!("{}:", label));
+        for value in values {
+            say!(" {:>14}", value);
+        }
+        sayln!();
+    }
+    sayln!();
 }
 
 // ==================== REAL TPS TESTING FUNCTIONS ====================
@@ -579,6 +2086,7 @@ struct FaucetResponse {
     success: bool,
     message: String,
     tx_hash: Option<String>,
+    #[allow(dead_code)]
     amount: String,
 }
 
@@ -586,6 +2094,7 @@ struct FaucetResponse {
 struct FaucetStatus {
     status: String,
     active_validator: String,
+    #[allow(dead_code)]
     pending_txs: usize,
     drip_amount: String,
     rate_limit_seconds: i64,
@@ -651,71 +2160,362 @@ fn generate_test_address(seed: u64) -> String {
     addresses[(seed as usize) % addresses.len()].to_string()
 }
 
-/// Run real TPS test against live network using faucet
-fn run_real_tps_test(faucet_url: &str, validators: &[String], tx_count: usize) {
-    println!();
-    println!(
+/// Request `tx_count` drips one at a time
+///
+/// Returns the successful and failed counts, and each transaction hash with
+/// when its drip was requested, in seconds since `start`.
+fn submit_via_faucet(faucet_url: &str, tx_count: usize, start: Instant) -> (usize, usize, Vec<(String, f64)>) {
+    let mut successful_txs = 0;
+    let mut failed_txs = 0;
+    let mut tx_hashes: Vec<(String, f64)> = Vec::new();
+
+    for i in 0..tx_count {
+        // Generate unique address for each request (to avoid rate limiting)
+        let address = generate_test_address(i as u64);
+
+        say!("  [{}/{}] {} ... ", i + 1, tx_count, &address[..20]);
+        ui::flush();
+
+        let requested = start.elapsed().as_secs_f64();
+        match request_faucet_drip(faucet_url, &address) {
+            Ok(response) => {
+                if response.success {
+                    sayln!("{}", "OK".green());
+                    if let Some(hash) = response.tx_hash {
+                        tx_hashes.push((hash, requested));
+                    }
+                    successful_txs += 1;
+                } else {
+                    sayln!("{} ({})", "FAILED".red(), response.message);
+                    failed_txs += 1;
+
+                    // If rate limited, wait
+                    if response.message.contains("Rate limited") {
+                        sayln!("    {} Waiting for rate limit...", "⏳".yellow());
+                        std::thread::sleep(Duration::from_secs(5));
+                    }
+                }
+            }
+            Err(e) => {
+                sayln!("{} ({})", "ERROR".red(), e);
+                failed_txs += 1;
+            }
+        }
+
+        // Small delay between requests to not overwhelm the faucet
+        if i < tx_count - 1 {
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    (successful_txs, failed_txs, tx_hashes)
+}
+
+/// `--direct` settings
+struct DirectConfig {
+    scheme: Scheme,
+    format: ExtrinsicFormat,
+    /// (public_key, secret_key) from `--signer-key`
+    keypair: Option<(Vec<u8>, Vec<u8>)>,
+    connections: usize,
+    /// `--load` profile and duration in seconds
+    load: Option<(LoadProfile, f64)>,
+}
+
+/// Read a `--signer-key` file: hex public key, then hex secret key
+fn load_signer_key(path: &std::path::Path) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut keys = text.lines().map(str::trim).filter(|line| !line.is_empty()).map(|line| {
+        hex::decode(line.trim_start_matches("0x")).map_err(|e| format!("{}: invalid hex key: {}", path.display(), e))
+    });
+    match (keys.next(), keys.next()) {
+        (Some(public_key), Some(secret_key)) => Ok((public_key?, secret_key?)),
+        _ => Err(format!("{}: expected a public key line and a secret key line", path.display())),
+    }
+}
+
+/// Signs `tx_count` self-transfers for `--direct`, returning the run so far and the encoded extrinsics
+struct DirectSigner<'a> {
+    config: &'a DirectConfig,
+    /// Validator the genesis hash, runtime version and nonce come from
+    url: &'a str,
+    tx_count: usize,
+}
+
+impl SchemeVisitor for DirectSigner<'_> {
+    type Output = Result<(DirectSubmission, Vec<Vec<u8>>), String>;
+
+    fn visit<S: SignatureScheme>(self) -> Self::Output {
+        let keypair = match &self.config.keypair {
+            Some((public_key, secret_key))
+                if public_key.len() != S::PUBLIC_KEY_BYTES || secret_key.len() != S::SECRET_KEY_BYTES =>
+            {
+                return Err(format!(
+                    "--signer-key is not a {} keypair (expected {} and {} byte keys)",
+                    S::NAME,
+                    S::PUBLIC_KEY_BYTES,
+                    S::SECRET_KEY_BYTES
+                ));
+            }
+            Some(keypair) => keypair.clone(),
+            None => S::keypair(),
+        };
+        let signer = extrinsic::account_id(&keypair.0);
+        let address = extrinsic::ss58(&signer);
+
+        let (chain, first_nonce) = block_on(async {
+            let mut client = ws::WsClient::connect(self.url).await?;
+            let chain = submit::chain_info(&mut client).await?;
+            let nonce = submit::next_nonce(&mut client, &address).await?;
+            client.close().await;
+            Ok::<_, String>((chain, nonce))
+        })??;
+        sayln!("  {:25} {}", "Signer:", address);
+        sayln!(
+            "  {:25} spec {}, transaction {}, next nonce {}",
+            "Runtime:",
+            chain.spec_version,
+            chain.transaction_version,
+            first_nonce
+        );
+
+        // A 1-unit transfer to itself, so the run only spends fees
+        let format = self.config.format;
+        let call = format.transfer(&signer, 1);
+        sayln!("{}", format!("Signing {} {} transfers...", self.tx_count, S::NAME).yellow());
+        let sign_start = Instant::now();
+        let extrinsics: Vec<Vec<u8>> = (0..self.tx_count)
+            .into_par_iter()
+            .map(|i| {
+                let nonce = first_nonce + i as u64;
+                let payload = format.signing_payload(&chain, &call, nonce);
+                // The runtime verifies a detached signature over the payload
+                let signature = SignedTransaction::<S>::sign(&payload, &keypair.1, VerifyPath::Detached);
+                format.signed(&signer, &signature, &call, nonce)
+            })
+            .collect();
+        let sign_time = sign_start.elapsed();
+        print_rate("Sign (parallel)", "sig/s", self.tx_count, sign_time, None);
+        sayln!();
+
+        let submission = DirectSubmission {
+            scheme: S::ID.to_string(),
+            signer: address,
+            first_nonce,
+            spec_version: chain.spec_version,
+            transaction_version: chain.transaction_version,
+            extrinsic_bytes: extrinsics.first().map_or(0, Vec::len),
+            sign_secs: sign_time.as_secs_f64(),
+            connections: self.config.connections.max(1),
+            ..Default::default()
+        };
+        Ok((submission, extrinsics))
+    }
+}
+
+fn print_inclusion(inclusion: &InclusionStats) {
+    let secs = |ms: f64| ms / 1000.0;
+    sayln!("{}", "Transaction Lifecycle:".yellow().bold());
+    sayln!(
+        "  {:25} {} ({} ready, {} in block, {} finalized)",
+        "Tracked:",
+        inclusion.tracked,
+        inclusion.ready,
+        inclusion.in_block,
+        inclusion.finalized
+    );
+    for (label, row) in [
+        ("Submit → ready:", &inclusion.submit_to_ready),
+        ("Submit → in block:", &inclusion.submit_to_in_block),
+        ("Submit → finalized:", &inclusion.submit_to_finalized),
+        ("In block → finalized:", &inclusion.in_block_to_finalized),
+    ] {
+        if row.count > 0 {
+            sayln!(
+                "  {:25} p50 {:.2}s, p95 {:.2}s, p99 {:.2}s, max {:.2}s",
+                label,
+                secs(row.p50_ms),
+                secs(row.p95_ms),
+                secs(row.p99_ms),
+                secs(row.max_ms)
+            );
+        }
+    }
+    if !inclusion.missing.is_empty() {
+        sayln!("  {:25} {}", "Never landed:", format!("{}", inclusion.missing.len()).red().bold());
+        for missing in inclusion.missing.iter().take(10) {
+            sayln!("    {} ({})", missing.hash.dimmed(), missing.status);
+        }
+        if inclusion.missing.len() > 10 {
+            sayln!("    ... and {} more", inclusion.missing.len() - 10);
+        }
+    }
+    if !inclusion.skipped_blocks.is_empty() {
+        sayln!("  {:25} {}", "Blocks not scanned:", format!("{}", inclusion.skipped_blocks.len()).yellow().bold());
+        for skipped in inclusion.skipped_blocks.iter().take(10) {
+            sayln!("    #{} ({})", skipped.number, skipped.error);
+        }
+        if inclusion.skipped_blocks.len() > 10 {
+            sayln!("    ... and {} more", inclusion.skipped_blocks.len() - 10);
+        }
+    }
+    sayln!();
+}
+
+fn print_load_curve(curve: &LoadCurve) {
+    sayln!("{}", "Offered vs Included:".yellow().bold());
+    sayln!(
+        "  {:25} {} over {:.0}s, {:.1}s windows, {:.1}s blocks",
+        "Profile:",
+        curve.profile,
+        curve.duration_secs,
+        curve.window_secs,
+        curve.block_secs
+    );
+    sayln!(
+        "  {:>13} {:>10} {:>10} {:>10} {:>8} {:>12}",
+        "Window (s)",
+        "Offered",
+        "Accepted",
+        "Included",
+        "Landed",
+        "p50 incl."
+    );
+    for window in &curve.windows {
+        sayln!(
+            "  {:>13} {:>10.1} {:>10.1} {:>10.1} {:>7.0}% {:>11.2}s",
+            format!("{:.1}-{:.1}", window.start_secs, window.end_secs),
+            window.offered_tps,
+            window.accepted_tps,
+            window.included_tps,
+            window.landed * 100.0,
+            window.inclusion_p50_ms / 1000.0
+        );
+    }
+    let max_offered = curve.windows.iter().map(|w| w.offered_tps).fold(0.0, f64::max);
+    match curve.knee_tps {
+        Some(knee) => sayln!("  {:25} {}", "Saturation knee:", format!("~{:.1} TPS offered", knee).red().bold()),
+        None if curve.saturated => sayln!("  {:25} {}", "Saturation knee:", "behind from the first window".red().bold()),
+        None => sayln!(
+            "  {:25} {}",
+            "Saturation knee:",
+            format!("none, kept up to {:.1} TPS offered", max_offered).green()
+        ),
+    }
+    sayln!("  {:25} {:.1} TPS", "Peak included:", curve.peak_included_tps);
+    sayln!("  {:25} {:.1} ms", "Max send lag:", curve.max_send_lag_ms);
+    sayln!();
+}
+
+fn print_direct_submission(submission: &DirectSubmission) {
+    sayln!("{}", "Direct Submission:".yellow().bold());
+    sayln!(
+        "  {:25} {} ({} bytes per extrinsic)",
+        "Signer:",
+        submission.signer,
+        submission.extrinsic_bytes
+    );
+    sayln!(
+        "  {:25} {} accepted, {} rejected, {} failed",
+        "Node answers:",
+        format!("{}", submission.accepted).green().bold(),
+        format!("{}", submission.rejected).red(),
+        format!("{}", submission.failed).red()
+    );
+    for validator in &submission.validators {
+        sayln!(
+            "  {:25} {} accepted, {} rejected, {} failed",
+            validator.url,
+            validator.accepted,
+            validator.rejected,
+            validator.failed
+        );
+    }
+    if !submission.reasons.is_empty() {
+        sayln!("  {}", "Rejection reasons:".dimmed());
+        for reason in &submission.reasons {
+            sayln!("    {:>6} × {}", reason.count, reason.reason);
+        }
+    }
+    sayln!();
+}
+
+/// Run real TPS test against live network, through the faucet or with `--direct` submission
+fn run_real_tps_test(
+    faucet_url: &str,
+    validators: &[String],
+    tx_count: usize,
+    direct: Option<&DirectConfig>,
+    confirm: Duration,
+) -> Option<RealTpsMetrics> {
+    sayln!();
+    sayln!(
         "{}",
         "╔══════════════════════════════════════════════════════════════════╗".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "║     REAL NETWORK TPS TEST                                        ║".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "║     Testing ACTUAL transactions on live network                  ║".cyan()
     );
-    println!(
+    sayln!(
         "{}",
         "╚══════════════════════════════════════════════════════════════════╝".cyan()
     );
-    println!();
+    sayln!();
 
     // Check faucet status
-    println!("{}", "Checking faucet status...".yellow());
-    match get_faucet_status(faucet_url) {
-        Ok(status) => {
-            println!("  Status: {}", status.status.green());
-            println!("  Active validator: {}", status.active_validator);
-            println!("  Drip amount: {}", status.drip_amount);
-            println!("  Rate limit: {}s", status.rate_limit_seconds);
-            println!();
-        }
-        Err(e) => {
-            println!("{}", format!("Faucet not available: {}", e).red());
-            println!("Please ensure the faucet is running at {}", faucet_url);
-            return;
+    if direct.is_none() {
+        sayln!("{}", "Checking faucet status...".yellow());
+        match get_faucet_status(faucet_url) {
+            Ok(status) => {
+                sayln!("  Status: {}", status.status.green());
+                sayln!("  Active validator: {}", status.active_validator);
+                sayln!("  Drip amount: {}", status.drip_amount);
+                sayln!("  Rate limit: {}s", status.rate_limit_seconds);
+                sayln!();
+            }
+            Err(e) => {
+                sayln!("{}", format!("Faucet not available: {}", e).red());
+                sayln!("Please ensure the faucet is running at {}", faucet_url);
+                return None;
+            }
         }
     }
 
     // Check validators
-    println!("{}", "Checking validator connectivity...".yellow());
+    sayln!("{}", "Checking validator connectivity...".yellow());
     let mut online_validators: Vec<String> = Vec::new();
+    let mut statuses = Vec::new();
 
     for url in validators {
-        print!("  {} ... ", url);
-        std::io::Write::flush(&mut std::io::stdout()).unwrap();
+        say!("  {} ... ", url);
+        ui::flush();
 
-        match check_validator_health(url) {
+        let health = check_validator_health(url);
+        statuses.push(validator_status(url, &health));
+        match health {
             Ok(health) => {
                 if !health.is_syncing {
-                    println!("{} ({} peers)", "ONLINE".green().bold(), health.peers);
+                    sayln!("{} ({} peers)", "ONLINE".green().bold(), health.peers);
                     online_validators.push(url.clone());
                 } else {
-                    println!("{}", "SYNCING".yellow());
+                    sayln!("{}", "SYNCING".yellow());
                 }
             }
             Err(e) => {
-                println!("{} ({})", "OFFLINE".red(), e);
+                sayln!("{} ({})", "OFFLINE".red(), e);
             }
         }
     }
 
     if online_validators.is_empty() {
-        println!();
-        println!("{}", "ERROR: No validators online!".red());
-        return;
+        sayln!();
+        sayln!("{}", "ERROR: No validators online!".red());
+        return None;
     }
 
     let primary_validator = &online_validators[0];
@@ -724,74 +2524,121 @@ fn run_real_tps_test(faucet_url: &str, validators: &[String], tx_count: usize) {
     let start_block = match get_block_number(primary_validator) {
         Ok(b) => b,
         Err(e) => {
-            println!("{}", format!("Failed to get block number: {}", e).red());
-            return;
+            sayln!("{}", format!("Failed to get block number: {}", e).red());
+            return None;
         }
     };
 
-    println!();
-    println!("{}", format!("━━━ Starting Real TPS Test ━━━").blue().bold());
-    println!("  Target transactions: {}", tx_count);
-    println!("  Starting block: #{}", start_block);
-    println!();
-
-    // Submit transactions via faucet
-    println!("{}", "Submitting transactions...".yellow());
-    let start_time = Instant::now();
-    let mut successful_txs = 0;
-    let mut failed_txs = 0;
-    let mut tx_hashes: Vec<String> = Vec::new();
-
-    for i in 0..tx_count {
-        // Generate unique address for each request (to avoid rate limiting)
-        let address = generate_test_address(i as u64);
-
-        print!("  [{}/{}] {} ... ", i + 1, tx_count, &address[..20]);
-        std::io::Write::flush(&mut std::io::stdout()).unwrap();
+    // An open-loop run sends on the profile's schedule instead of a fixed count
+    let load = direct.and_then(|config| config.load);
+    let schedule = load.map(|(profile, duration)| profile.schedule(duration));
+    let tx_count = schedule.as_ref().map_or(tx_count, Vec::len);
 
-        match request_faucet_drip(faucet_url, &address) {
-            Ok(response) => {
-                if response.success {
-                    println!("{}", "OK".green());
-                    if let Some(hash) = response.tx_hash {
-                        tx_hashes.push(hash);
-                    }
-                    successful_txs += 1;
-                } else {
-                    println!("{} ({})", "FAILED".red(), response.message);
-                    failed_txs += 1;
+    sayln!();
+    sayln!("{}", "━━━ Starting Real TPS Test ━━━".blue().bold());
+    if let Some((profile, duration)) = load {
+        sayln!("  Load profile: {} over {}s", profile, duration);
+    }
+    sayln!("  Target transactions: {}", tx_count);
+    sayln!("  Starting block: #{}", start_block);
+    sayln!();
+
+    // Build and sign the extrinsics before the clock starts
+    let prepared = match direct {
+        Some(config) => match config.scheme.visit(DirectSigner { config, url: primary_validator, tx_count }) {
+            Ok(prepared) => Some(prepared),
+            Err(e) => {
+                sayln!("{}", format!("Failed to prepare extrinsics: {}", e).red());
+                return None;
+            }
+        },
+        None => None,
+    };
 
-                    // If rate limited, wait
-                    if response.message.contains("Rate limited") {
-                        println!("    {} Waiting for rate limit...", "⏳".yellow());
-                        std::thread::sleep(Duration::from_secs(5));
-                    }
+    sayln!("{}", "Submitting transactions and following them to finality...".yellow());
+    let start_time = Instant::now();
+    let mut load_curve = None;
+    // Hashes as submitted, reported when inclusion tracking fails
+    let mut submitted_hashes = Vec::new();
+    let (successful_txs, failed_txs, submission_secs, traces, submission) = match prepared {
+        Some((mut submission, extrinsics)) => {
+            let connections = submission.connections;
+            let submit_all = submit::submit_all(
+                &online_validators,
+                &extrinsics,
+                schedule.as_deref(),
+                connections,
+                start_time,
+                confirm,
+            );
+            let (outcomes, submission_secs) = match block_on(submit_all) {
+                Ok(submitted) => submitted,
+                Err(e) => {
+                    sayln!("{}", format!("Submission failed: {}", e).red());
+                    return None;
                 }
+            };
+            submission.record(&online_validators, &outcomes);
+            if let (Some((profile, duration)), Some(schedule)) = (load, &schedule) {
+                let sent: Vec<(f64, Option<&TxTrace>)> = schedule
+                    .iter()
+                    .zip(&outcomes)
+                    .map(|(&at, (_, outcome))| match outcome {
+                        Outcome::Accepted(trace) => (at, Some(trace)),
+                        _ => (at, None),
+                    })
+                    .collect();
+                load_curve = Some(LoadCurve::measure(&profile, duration, &sent));
             }
-            Err(e) => {
-                println!("{} ({})", "ERROR".red(), e);
-                failed_txs += 1;
-            }
+            let traces = outcomes
+                .into_iter()
+                .filter_map(|(_, outcome)| match outcome {
+                    // Unanswered submissions were sent too: they count as never landed
+                    Outcome::Accepted(trace) | Outcome::Unanswered(trace) => Some(trace),
+                    _ => None,
+                })
+                .collect();
+            let failed = submission.rejected + submission.failed;
+            (submission.accepted, failed, submission_secs, Ok((traces, Vec::new())), Some(submission))
         }
-
-        // Small delay between requests to not overwhelm the faucet
-        if i < tx_count - 1 {
-            std::thread::sleep(Duration::from_millis(100));
+        None => {
+            // The faucet only returns hashes, so scan blocks for them alongside the drips
+            let (hashes_tx, hashes_rx) = tokio::sync::oneshot::channel();
+            let url = primary_validator.clone();
+            let scanner = std::thread::spawn(move || {
+                block_on(inclusion::scan(&url, start_time, start_block, hashes_rx, confirm))?
+            });
+            let (successful, failed, submitted) = submit_via_faucet(faucet_url, tx_count, start_time);
+            let submission_secs = start_time.elapsed().as_secs_f64();
+            submitted_hashes = submitted.iter().map(|(hash, _)| hash.clone()).collect();
+            let _ = hashes_tx.send(submitted);
+            let traces = scanner.join().unwrap_or_else(|_| Err("block scan panicked".to_string()));
+            (successful, failed, submission_secs, traces, None)
         }
-    }
-
-    let submission_time = start_time.elapsed();
+    };
 
-    // Wait for transactions to be included in blocks
-    println!();
-    println!("{}", "Waiting for block confirmation...".yellow());
-    std::thread::sleep(Duration::from_secs(12)); // Wait for ~2 blocks
+    let submission_time = Duration::from_secs_f64(submission_secs);
+    let (traces, skipped_blocks, tx_hashes) = match traces {
+        Ok((traces, skipped_blocks)) => {
+            let tx_hashes = traces.iter().map(|t| t.hash.clone()).collect();
+            (traces, skipped_blocks, tx_hashes)
+        }
+        Err(e) => {
+            // Without a WebSocket, fall back to waiting for transactions to be included in blocks
+            sayln!();
+            sayln!("{}", format!("Inclusion tracking failed ({}); waiting for block confirmation...", e).yellow());
+            std::thread::sleep(Duration::from_secs(12)); // Wait for ~2 blocks
+            (Vec::new(), Vec::new(), submitted_hashes)
+        }
+    };
+    let inclusion = (!traces.is_empty())
+        .then(|| InclusionStats { skipped_blocks, ..InclusionStats::from_traces(&traces) });
 
     // Get ending block
     let end_block = match get_block_number(primary_validator) {
         Ok(b) => b,
         Err(e) => {
-            println!("{}", format!("Failed to get block number: {}", e).red());
+            sayln!("{}", format!("Failed to get block number: {}", e).red());
             start_block
         }
     };
@@ -813,86 +2660,137 @@ fn run_real_tps_test(faucet_url: &str, validators: &[String], tx_count: usize) {
     };
 
     // Print results
-    println!();
-    println!("{}", "━━━ REAL TPS RESULTS ━━━".blue().bold());
-    println!();
-    println!(
+    sayln!();
+    sayln!("{}", "━━━ REAL TPS RESULTS ━━━".blue().bold());
+    sayln!();
+    sayln!(
         "  {:30} {}",
         "Transactions submitted:",
         format!("{}", tx_count).white()
     );
-    println!(
+    sayln!(
         "  {:30} {}",
         "Successful:",
         format!("{}", successful_txs).green().bold()
     );
-    println!(
+    sayln!(
         "  {:30} {}",
         "Failed:",
         format!("{}", failed_txs).red()
     );
-    println!();
-    println!(
+    sayln!();
+    sayln!(
         "  {:30} {}",
         "Submission time:",
         format!("{:.2}s", submission_time.as_secs_f64()).white()
     );
-    println!(
+    sayln!(
         "  {:30} {}",
         "Total time (with confirmation):",
         format!("{:.2}s", total_time.as_secs_f64()).white()
     );
-    println!();
-    println!(
+    sayln!();
+    sayln!(
         "  {:30} {}",
         "Blocks produced:",
         format!("{}", blocks_produced).yellow()
     );
-    println!(
+    sayln!(
         "  {:30} {}",
         "Start → End block:",
         format!("#{} → #{}", start_block, end_block).white()
     );
-    println!();
-    println!(
+    sayln!();
+    sayln!(
         "  {:30} {}",
         "Submission TPS:",
         format!("{:.2} TPS", submission_tps).green().bold()
     );
-    println!(
+    sayln!(
         "  {:30} {}",
         "Effective TPS:",
         format!("{:.2} TPS", effective_tps).yellow().bold()
     );
-    println!();
+    sayln!();
+
+    if let Some(submission) = &submission {
+        print_direct_submission(submission);
+    }
+    if let Some(inclusion) = &inclusion {
+        print_inclusion(inclusion);
+    }
+    if let Some(curve) = &load_curve {
+        print_load_curve(curve);
+    }
 
     if !tx_hashes.is_empty() {
-        println!("{}", "Sample transaction hashes:".dimmed());
+        sayln!("{}", "Sample transaction hashes:".dimmed());
         for hash in tx_hashes.iter().take(3) {
-            println!("  {}", hash.dimmed());
+            sayln!("  {}", hash.dimmed());
         }
     }
 
-    println!();
-    println!("{}", "Note:".dimmed());
-    println!(
-        "{}",
-        "  - Faucet rate limiting may affect results".dimmed()
-    );
-    println!(
-        "{}",
-        "  - For accurate TPS, use multiple addresses".dimmed()
-    );
-    println!(
+    sayln!();
+    sayln!("{}", "Note:".dimmed());
+    if direct.is_some() {
+        sayln!(
+            "{}",
+            "  - Accepted means in a validator's pool, not yet in a block".dimmed()
+        );
+        sayln!(
+            "{}",
+            "  - A fresh keypair holds no funds; use --signer-key with a funded account".dimmed()
+        );
+    } else {
+        sayln!(
+            "{}",
+            "  - Faucet rate limiting may affect results".dimmed()
+        );
+        sayln!(
+            "{}",
+            "  - For accurate TPS, use multiple addresses".dimmed()
+        );
+    }
+    sayln!(
         "{}",
         "  - SPHINCS+ verification happens during block production".dimmed()
     );
-    println!();
+    sayln!();
+
+    Some(RealTpsMetrics {
+        validators: statuses,
+        submitted: tx_count,
+        successful: successful_txs,
+        failed: failed_txs,
+        submission_secs: submission_time.as_secs_f64(),
+        total_secs: total_time.as_secs_f64(),
+        start_block,
+        end_block,
+        blocks_produced,
+        submission_tps,
+        effective_tps,
+        tx_hashes,
+        direct: submission,
+        inclusion,
+        traces,
+        load: load_curve,
+    })
 }
 
 fn main() {
     let args = Args::parse();
 
+    let format = OutputFormat::resolve(args.output, args.out.as_deref());
+    if format != OutputFormat::Table && args.out.is_none() {
+        // Keep stdout clean for the machine-readable report
+        ui::route_to_stderr();
+    }
+
+    if let Some(reports) = &args.compare {
+        let code = run_compare(&args.baseline_dir, &reports[0], &reports[1], args.regression_threshold);
+        std::process::exit(code);
+    }
+
     // Parse validators
     let validators: Vec<String> = args
         .validators
@@ -902,56 +2800,271 @@ fn main() {
 
     // If real TPS mode, run actual transaction test
     if args.real_tps {
-        run_real_tps_test(&args.faucet, &validators, args.transactions);
+        let mut report = BenchmarkReport::new(RunConfig {
+            mode: "real-tps".to_string(),
+            tx_counts: vec![args.transactions],
+            validators: validators.clone(),
+            ..Default::default()
+        });
+        if !(args.confirm_timeout >= 0.0 && args.confirm_timeout.is_finite()) {
+            eprintln!("{}", "--confirm-timeout must be a non-negative number of seconds".red());
+            std::process::exit(1);
+        }
+        if args.load.is_some() && !(args.load_duration > 0.0 && args.load_duration.is_finite()) {
+            eprintln!("{}", "--load-duration must be a positive number of seconds".red());
+            std::process::exit(1);
+        }
+        let direct = if args.direct {
+            let keypair = match args.signer_key.as_deref().map(load_signer_key).transpose() {
+                Ok(keypair) => keypair,
+                Err(e) => {
+                    eprintln!("{}", e.red());
+                    std::process::exit(1);
+                }
+            };
+            Some(DirectConfig {
+                scheme: args.scheme[0],
+                format: args.extrinsic_format.unwrap_or_default(),
+                keypair,
+                connections: args.submit_connections,
+                load: args.load.map(|profile| (profile, args.load_duration)),
+            })
+        } else {
+            None
+        };
+        report.real_tps = run_real_tps_test(
+            &args.faucet,
+            &validators,
+            args.transactions,
+            direct.as_ref(),
+            Duration::from_secs_f64(args.confirm_timeout),
+        );
+        finish_report(&report, &args, format);
+        return;
+    }
+
+    // Simulated network, calibrated from this machine or a stored run
+    if args.simulate {
+        let mut report = BenchmarkReport::new(RunConfig {
+            mode: "simulate".to_string(),
+            ..Default::default()
+        });
+        let validator_specs = if args.validator_spec.is_empty() {
+            vec![ValidatorSpec { count: 3, ..ValidatorSpec::with_cores(num_cpus::get()) }]
+        } else {
+            args.validator_spec.clone()
+        };
+        let simulated = calibrate(&args).and_then(|costs| {
+            let projection = CapacityProjection::project(&costs.rate, &validator_specs);
+            report.config.schemes = vec![costs.rate.scheme.clone()];
+            report.config.tx_counts = vec![costs.rate.tx_count];
+            let result = run_simulation(&args, &projection, &costs);
+            report.capacity = vec![projection];
+            result
+        });
+        match simulated {
+            Ok((network, simulation, aura)) => {
+                report.network = Some(network);
+                report.simulation = Some(simulation);
+                report.simulation_baseline = aura;
+            }
+            Err(e) => {
+                eprintln!("{}", format!("Simulation failed: {}", e).red());
+                std::process::exit(1);
+            }
+        }
+        print_capacity(&report.capacity);
+        finish_report(&report, &args, format);
         return;
     }
 
     // If network mode, run network benchmark (block monitoring only)
     if args.network {
-        run_network_benchmark(&validators, 30); // 30 second benchmark
+        let mut report = BenchmarkReport::new(RunConfig {
+            mode: "network".to_string(),
+            validators: validators.clone(),
+            ..Default::default()
+        });
+        if let Some(name) = &args.capacity_from {
+            if args.validator_spec.is_empty() {
+                eprintln!("{}", "--capacity-from needs --validator-spec to describe the remote validators".red());
+                std::process::exit(1);
+            }
+            match BaselineStore::new(&args.baseline_dir).load(name) {
+                Ok(measured) => {
+                    report.capacity = measured
+                        .schemes
+                        .iter()
+                        .map(|row| CapacityProjection::project(&row.into(), &args.validator_spec))
+                        .collect();
+                }
+                Err(e) => {
+                    eprintln!("{}", format!("Failed to load {}: {}", name, e).red());
+                    std::process::exit(1);
+                }
+            }
+        }
+        report.network = run_network_benchmark(&validators, 30, report.capacity.first()); // 30 second benchmark
+        if !report.capacity.is_empty() {
+            print_capacity(&report.capacity);
+        }
+        finish_report(&report, &args, format);
         return;
     }
 
-    print_header();
-
-    // Determine transaction counts based on mode
-    let tx_counts = if args.quick {
-        vec![10, 25]
-    } else if args.full {
-        vec![10, 50, 100, 200, 500]
-    } else {
-        vec![args.transactions]
+    // Suites to run: a scenario's (--quick and --full are bundled ones), or the command line as is
+    let scenario = args
+        .scenario
+        .clone()
+        .or_else(|| args.quick.then(|| "quick".to_string()))
+        .or_else(|| args.full.then(|| "full".to_string()));
+    let suites: Vec<Suite> = match &scenario {
+        Some(name) => match Scenario::load(name).and_then(|s| s.select(&args.suite).map(|v| v.into_iter().cloned().collect())) {
+            Ok(suites) => suites,
+            Err(e) => {
+                eprintln!("{}", format!("Failed to load scenario: {}", e).red());
+                std::process::exit(1);
+            }
+        },
+        None => vec![Suite::default()],
     };
+    let plans: Vec<SuitePlan> = suites.iter().map(|suite| SuitePlan::resolve(suite, &args)).collect();
+
+    let mut all_schemes: Vec<Scheme> = Vec::new();
+    let mut all_tx_counts: Vec<usize> = Vec::new();
+    let mut all_sweep_segments: Vec<usize> = Vec::new();
+    for plan in &plans {
+        for &scheme in &plan.schemes {
+            if !all_schemes.contains(&scheme) {
+                all_schemes.push(scheme);
+            }
+        }
+        for &tx_count in &plan.tx_counts {
+            if !all_tx_counts.contains(&tx_count) {
+                all_tx_counts.push(tx_count);
+            }
+        }
+        for &segments in &plan.sweep_segments {
+            if !all_sweep_segments.contains(&segments) {
+                all_sweep_segments.push(segments);
+            }
+        }
+    }
+
+    print_header(&all_schemes);
+
+    let pool_threads = args.pool_threads.unwrap_or_else(num_cpus::get);
+    let mut report = BenchmarkReport::new(RunConfig {
+        mode: "local".to_string(),
+        schemes: all_schemes.iter().map(|s| s.info().id.to_string()).collect(),
+        tx_counts: all_tx_counts,
+        segments: args.segments,
+        keypairs: args.keypairs,
+        iterations: args.iterations.max(1),
+        warmup: args.warmup,
+        pool_threads,
+        torus: args.torus.map(|mesh| mesh.dims()),
+        both_paths: args.both_paths,
+        validators: Vec::new(),
+        scenario: scenario.clone(),
+        suites: if scenario.is_some() { suites.clone() } else { Vec::new() },
+        sweep: Some(args.sweep.clone().unwrap_or(SweepSpec::PowersOfTwo { max: args.segments })),
+        sweep_segments: all_sweep_segments,
+        thread_sweep: args.thread_sweep.clone(),
+    });
+    print_environment(&report.environment);
+
+    let mut suite_assertions = Vec::new();
+    for plan in &plans {
+        if let Some(name) = &plan.name {
+            sayln!("{}", format!("▶ Suite: {}", name).cyan().bold());
+            if let Some(description) = &plan.description {
+                sayln!("  {}", description.dimmed());
+            }
+            sayln!();
+        }
+        sayln!(
+            "{} {} ({} segments)",
+            "Torus sweep:".yellow().bold(),
+            plan.sweep,
+            plan.sweep_segments.iter().map(|s| s.to_string()).collect::<Vec<_>>().join(", ")
+        );
+        sayln!();
+
+        let first_row = report.results.len();
+        for &scheme in &plan.schemes {
+            if plan.schemes.len() > 1 {
+                sayln!("{}", format!("════ {} ════", scheme).magenta().bold());
+                sayln!();
+            }
 
-    // Generate keypairs
-    println!("{}", "Generating SPHINCS+ keypairs...".yellow());
-    print!("  Creating {} keypairs... ", args.keypairs);
-    std::io::Write::flush(&mut std::io::stdout()).unwrap();
+            let (summaries, results) = scheme.visit(LocalBenchmark {
+                suite: plan.name.as_deref(),
+                tx_counts: &plan.tx_counts,
+                sweep: &plan.sweep_segments,
+                thread_sweep: &plan.thread_sweep,
+                segments: plan.segments,
+                torus: args.torus,
+                pool_threads,
+                trials: plan.trials,
+                keypairs: plan.keypairs,
+                payloads: &plan.payloads,
+                paths: &plan.paths,
+            });
+            report.schemes.extend(summaries);
+            report.results.extend(results);
+        }
 
-    let kp_start = Instant::now();
-    let keypairs = generate_keypairs(args.keypairs);
-    println!("{} ({:.2}s)", "Done".green(), kp_start.elapsed().as_secs_f64());
-    println!();
+        if !plan.assertions.is_empty() {
+            let name = plan.name.as_deref().unwrap_or_default();
+            suite_assertions.extend(plan.assertions.evaluate(&report.results[first_row..]).into_iter().map(|mut a| {
+                a.name = format!("{}/{}", name, a.name);
+                a.requirement = format!("[{}] {}", name, a.requirement);
+                a
+            }));
+        }
+    }
 
-    // Run benchmarks
-    for tx_count in tx_counts {
-        run_benchmark(tx_count, args.segments, &keypairs);
+    if all_schemes.len() > 1 || plans.iter().any(|p| p.paths.len() > 1) {
+        print_scheme_comparison(&report.schemes);
     }
 
     // Summary
-    println!("{}", "╔══════════════════════════════════════════════════════════════════╗".cyan());
-    println!("{}", "║  SUMMARY                                                         ║".cyan());
-    println!("{}", "╚══════════════════════════════════════════════════════════════════╝".cyan());
-    println!();
-    println!("  {}: {} cores detected", "System".yellow(), num_cpus::get());
-    println!("  {}: ~{} TPS per core (250ms verification)", "Theoretical".yellow(), 4);
-    println!("  {}: ~{} TPS with all cores", "Maximum".yellow(), num_cpus::get() * 4);
-    println!();
-    println!("{}", "Network Scaling:".yellow().bold());
-    println!("  - 3 validators × 8 cores  = ~96 TPS theoretical");
-    println!("  - 10 validators × 16 cores = ~640 TPS theoretical");
-    println!("  - With pre-verification: transactions appear instant to users");
-    println!();
-    println!("{}", "Learn more: https://github.com/Paraxiom/quantumharmony".dimmed());
-    println!();
+    sayln!("{}", "╔══════════════════════════════════════════════════════════════════╗".cyan());
+    sayln!("{}", "║  SUMMARY                                                         ║".cyan());
+    sayln!("{}", "╚══════════════════════════════════════════════════════════════════╝".cyan());
+    sayln!();
+    sayln!("  {}: {} cores detected", "System".yellow(), num_cpus::get());
+    sayln!();
+    let validator_specs = if args.validator_spec.is_empty() {
+        vec![ValidatorSpec::with_cores(num_cpus::get())]
+    } else {
+        args.validator_spec.clone()
+    };
+    report.capacity = report
+        .schemes
+        .iter()
+        .map(|row| CapacityProjection::project(&row.into(), &validator_specs))
+        .collect();
+    print_capacity(&report.capacity);
+    sayln!("{}", "Learn more: https://github.com/Paraxiom/quantumharmony".dimmed());
+    sayln!();
+
+    let assertions = Assertions {
+        min_sequential_tps: args.assert_min_sequential_tps,
+        min_speedup: args.assert_min_speedup.clone(),
+        max_p99_ms: args.assert_max_p99_ms,
+    };
+    report.assertions = suite_assertions;
+    if !assertions.is_empty() {
+        report.assertions.extend(assertions.evaluate(&report.results));
+    }
+    if !report.assertions.is_empty() {
+        print_assertions(&report.assertions);
+    }
+
+    finish_report(&report, &args, format);
+    if report.assertions.iter().any(|a| !a.passed) {
+        std::process::exit(EXIT_ASSERTION_FAILED);
+    }
 }
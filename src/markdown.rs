@@ -0,0 +1,533 @@
+//! Markdown report writer
+//!
+//! Renders a [`BenchmarkReport`] as GitHub-flavored Markdown for release notes
+//! and the README: environment fingerprint, one results table per measured
+//! transaction set (the numbers `print_result` prints), the scheme
+//! comparison, scalability fits, assertion outcomes, the capacity projection
+//! and any network results.
+
+use std::fmt::Write;
+
+use crate::report::{BenchmarkReport, StrategyRow};
+use crate::simulate::{self, Consensus, SimulationMetrics};
+
+/// Render `report` as a standalone Markdown document
+pub fn render(report: &BenchmarkReport) -> String {
+    let mut out = String::new();
+    let _ = write_report(&mut out, report);
+    out
+}
+
+fn write_report(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "# QuantumHarmony Benchmark Report")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "`sphincs-benchmark` v{}, {} mode, schema v{}, generated at Unix time {}.",
+        report.tool_version, report.config.mode, report.schema_version, report.generated_at
+    )?;
+    writeln!(out)?;
+
+    write_environment(out, report)?;
+    if !report.results.is_empty() {
+        write_results(out, report)?;
+    }
+    if report.schemes.len() > 1 {
+        write_scheme_comparison(out, report)?;
+    }
+    if report.schemes.iter().any(|s| s.scalability.is_some()) {
+        write_scalability(out, report)?;
+    }
+    if !report.assertions.is_empty() {
+        write_assertions(out, report)?;
+    }
+    if !report.capacity.is_empty() {
+        write_capacity(out, report)?;
+    }
+    write_network(out, report)?;
+    Ok(())
+}
+
+fn write_environment(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    let env = &report.environment;
+    let build = &env.build;
+    writeln!(out, "## Environment")?;
+    writeln!(out)?;
+    writeln!(out, "| | |")?;
+    writeln!(out, "|---|---|")?;
+    writeln!(out, "| CPU | {} |", env.cpu_model.as_deref().unwrap_or("unknown"))?;
+    writeln!(out, "| Cores | {} physical / {} logical |", env.physical_cores, env.logical_cores)?;
+    writeln!(out, "| CPU flags | {} |", env.notable_cpu_flags().join(" "))?;
+    match env.memory_gib() {
+        Some(gib) => writeln!(out, "| Memory | {:.1} GiB |", gib)?,
+        None => writeln!(out, "| Memory | unknown |")?,
+    }
+    writeln!(out, "| OS | {} {} {} |", env.os, env.arch, env.kernel.as_deref().unwrap_or(""))?;
+    writeln!(out, "| Build | v{} {} ({}) |", build.crate_version, build.git_commit, build.profile)?;
+    writeln!(out, "| Toolchain | {} |", build.rustc_version)?;
+    writeln!(out, "| Features | {} |", build.features.join(", "))?;
+    if !report.config.schemes.is_empty() {
+        writeln!(out, "| Schemes | {} |", report.config.schemes.join(", "))?;
+    }
+    if !report.config.sweep_segments.is_empty() {
+        let segments: Vec<String> = report.config.sweep_segments.iter().map(|s| s.to_string()).collect();
+        writeln!(out, "| Torus sweep | {} segments |", segments.join(", "))?;
+    }
+    writeln!(out)
+}
+
+fn write_results(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "## Results")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Mean of {} measured run(s) after {} warmup run(s); ± is the 95% confidence interval.",
+        report.config.iterations, report.config.warmup
+    )?;
+
+    // Rows arrive grouped by transaction set; start a table at each change
+    let mut current = None;
+    for row in &report.results {
+        let group = row.group_label();
+        if current.as_ref() != Some(&group) {
+            writeln!(out)?;
+            writeln!(out, "### {}", group)?;
+            current = Some(group);
+            writeln!(out)?;
+            writeln!(out, "| Strategy | Kind | TPS | ±95% | Speedup | p50 ms | p99 ms | Verified |")?;
+            writeln!(out, "|---|---|---:|---:|---:|---:|---:|---:|")?;
+        }
+        write_result_row(out, row)?;
+    }
+    writeln!(out)
+}
+
+fn write_result_row(out: &mut String, row: &StrategyRow) -> std::fmt::Result {
+    let speedup = if row.kind == "baseline" {
+        "baseline".to_string()
+    } else {
+        format!("{:.2}×", row.speedup)
+    };
+    writeln!(
+        out,
+        "| {} | {} | {:.0} | {:.0} | {} | {:.2} | {:.2} | {}/{} |",
+        row.strategy,
+        row.kind,
+        row.tps.mean,
+        row.tps.ci95,
+        speedup,
+        row.latency.p50_ms,
+        row.latency.p99_ms,
+        row.verified,
+        row.total
+    )
+}
+
+fn write_scheme_comparison(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "## Scheme Comparison")?;
+    writeln!(out)?;
+    writeln!(out, "| Scheme | Path | NIST | PK bytes | Sig bytes | Tx | Keygen/s | Sign/s | Verify TPS | Best parallel TPS |")?;
+    writeln!(out, "|---|---|---:|---:|---:|---:|---:|---:|---:|---:|")?;
+    for s in &report.schemes {
+        let level = if s.nist_level > 0 { s.nist_level.to_string() } else { "-".to_string() };
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {:.0} | {:.1} | {:.0} | {:.0} @ {} seg |",
+            s.scheme_name,
+            s.path,
+            level,
+            s.public_key_bytes,
+            s.signature_bytes,
+            s.tx_count,
+            s.keygen_par_per_sec,
+            s.sign_par_per_sec,
+            s.sequential_tps,
+            s.best_parallel_tps,
+            s.best_segments
+        )?;
+    }
+    writeln!(out)
+}
+
+fn write_scalability(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "## Scalability")?;
+    writeln!(out)?;
+    writeln!(out, "Flat rayon on a dedicated pool per thread count, fitted to Amdahl's law and the Universal Scalability Law.")?;
+    writeln!(out)?;
+    writeln!(out, "| Scheme | Path | Tx | Threads | 1-thread TPS | Amdahl σ | Ceiling | USL σ | USL κ | Peak | R² Amdahl / USL |")?;
+    writeln!(out, "|---|---|---:|---|---:|---:|---|---:|---:|---|---|")?;
+    for s in &report.schemes {
+        let Some(fit) = &s.scalability else { continue };
+        let threads: Vec<String> = fit.threads.iter().map(|t| t.to_string()).collect();
+        let (amdahl_sigma, ceiling, amdahl_r2) = match &fit.amdahl {
+            Some(a) => (
+                format!("{:.3}", a.serial_fraction),
+                match (a.max_speedup, a.max_tps) {
+                    (Some(speedup), Some(tps)) => format!("{:.1}× ({:.0} TPS)", speedup, tps),
+                    _ => "none".to_string(),
+                },
+                format!("{:.3}", a.r_squared),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        let (usl_sigma, usl_kappa, peak, usl_r2) = match &fit.usl {
+            Some(u) => (
+                format!("{:.4}", u.contention),
+                format!("{:.6}", u.coherency),
+                match (u.peak_threads, u.peak_tps) {
+                    (Some(threads), Some(tps)) => format!("{:.0} TPS at {:.0} threads", tps, threads),
+                    _ => "none".to_string(),
+                },
+                format!("{:.3}", u.r_squared),
+            ),
+            None => ("-".to_string(), "-".to_string(), "-".to_string(), "-".to_string()),
+        };
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {:.0} | {} | {} | {} | {} | {} | {} / {} |",
+            s.scheme_name,
+            s.path,
+            s.tx_count,
+            threads.join(", "),
+            fit.single_thread_tps,
+            amdahl_sigma,
+            ceiling,
+            usl_sigma,
+            usl_kappa,
+            peak,
+            amdahl_r2,
+            usl_r2
+        )?;
+    }
+    writeln!(out)
+}
+
+fn write_assertions(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "## Assertions")?;
+    writeln!(out)?;
+    writeln!(out, "| Result | Requirement | Worst observed |")?;
+    writeln!(out, "|---|---|---|")?;
+    for a in &report.assertions {
+        let observed = match (a.actual, &a.worst_row) {
+            (Some(actual), Some(row)) => format!("{:.3} ({})", actual, row),
+            _ => "not measured".to_string(),
+        };
+        let result = if a.passed { "✅ pass" } else { "❌ fail" };
+        writeln!(out, "| {} | {} | {} |", result, a.requirement, observed)?;
+    }
+    writeln!(out)
+}
+
+fn write_capacity(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    writeln!(out, "## Capacity Projection")?;
+    writeln!(out)?;
+    writeln!(out, "| Scheme | Path | Tx | Per-core TPS | Validators | Projected TPS |")?;
+    writeln!(out, "|---|---|---:|---:|---|---:|")?;
+    for p in &report.capacity {
+        let validators: Vec<String> = p
+            .validators
+            .iter()
+            .map(|v| {
+                format!(
+                    "{} × {} cores ({}s blocks, {}s weight): {:.1} TPS",
+                    v.spec.count, v.spec.cores, v.spec.block_time_secs, v.spec.block_weight_secs, v.projected_tps
+                )
+            })
+            .collect();
+        writeln!(
+            out,
+            "| {} | {} | {} | {:.1} | {} | {:.1} |",
+            p.scheme,
+            p.path,
+            p.tx_count,
+            p.per_core_tps,
+            validators.join("<br>"),
+            p.network_tps
+        )?;
+    }
+    writeln!(out)?;
+    writeln!(out, "Assumptions:")?;
+    writeln!(out)?;
+    let mut seen = Vec::new();
+    for assumption in report.capacity.iter().flat_map(|p| &p.assumptions) {
+        if !seen.contains(&assumption) {
+            writeln!(out, "- {}", assumption)?;
+            seen.push(assumption);
+        }
+    }
+    writeln!(out)
+}
+
+fn write_network(out: &mut String, report: &BenchmarkReport) -> std::fmt::Result {
+    if let Some(network) = &report.network {
+        writeln!(out, "## Network")?;
+        writeln!(out)?;
+        writeln!(out, "| | |")?;
+        writeln!(out, "|---|---|")?;
+        writeln!(
+            out,
+            "| Validators online | {} of {} |",
+            network.validators.iter().filter(|v| v.online).count(),
+            network.validators.len()
+        )?;
+        writeln!(out, "| Blocks | #{} → #{} ({} produced) |", network.start_block, network.end_block, network.blocks_produced)?;
+        writeln!(out, "| Elapsed | {:.2} s |", network.elapsed_secs)?;
+        writeln!(out, "| Block time | {:.2} s |", network.block_time_secs)?;
+        if let Some(tps) = network.measured_tps {
+            writeln!(
+                out,
+                "| Measured TPS | {:.2} ({} transactions, {} inherents) |",
+                tps, network.transactions, network.inherents
+            )?;
+        }
+        match &report.simulation {
+            Some(_) => writeln!(out, "| Simulated TPS | {:.1} |", network.estimated_tps)?,
+            None => writeln!(out, "| Estimated TPS | {:.0} (assumed block fill, not measured) |", network.estimated_tps)?,
+        }
+        if network.theoretical_max_tps > 0.0 {
+            writeln!(out, "| Projected capacity | {:.0} TPS (see Capacity Projection) |", network.theoretical_max_tps)?;
+        }
+        if let Some(heads) = &network.heads {
+            writeln!(out, "| Heads | {} new, {} finalized, {} reorgs |", heads.new_heads, heads.finalized_heads, heads.reorgs)?;
+            let block_time = &heads.block_time;
+            writeln!(
+                out,
+                "| Block time distribution | mean {:.2} s, p50 {:.2} s, p95 {:.2} s, max {:.2} s |",
+                block_time.mean_ms / 1000.0,
+                block_time.p50_ms / 1000.0,
+                block_time.p95_ms / 1000.0,
+                block_time.max_ms / 1000.0
+            )?;
+            match heads.longest_gap_until {
+                Some(until) => writeln!(
+                    out,
+                    "| Longest gap | {:.2} s (#{} → #{}), {} stalls |",
+                    heads.longest_gap_secs, heads.longest_gap_after, until, heads.stalls
+                )?,
+                None => writeln!(
+                    out,
+                    "| Longest gap | {:.2} s (after #{}, still open at the end), {} stalls |",
+                    heads.longest_gap_secs, heads.longest_gap_after, heads.stalls
+                )?,
+            }
+            let lag = &heads.finality_lag;
+            writeln!(
+                out,
+                "| Finality lag | p50 {:.2} s, p95 {:.2} s, max {:.2} s ({:.1} blocks on average) |",
+                lag.p50_ms / 1000.0,
+                lag.p95_ms / 1000.0,
+                lag.max_ms / 1000.0,
+                heads.finality_lag_blocks_mean
+            )?;
+        }
+        writeln!(out)?;
+        if !network.blocks.is_empty() {
+            writeln!(out, "| Block | Transactions | Inherents | Bytes |")?;
+            writeln!(out, "|---:|---:|---:|---:|")?;
+            for block in &network.blocks {
+                writeln!(out, "| #{} | {} | {} | {} |", block.number, block.transactions, block.inherents, block.bytes)?;
+            }
+            writeln!(out)?;
+        }
+    }
+    if let Some(sim) = &report.simulation {
+        writeln!(out, "## Simulation")?;
+        writeln!(out)?;
+        writeln!(out, "| | |")?;
+        writeln!(out, "|---|---|")?;
+        writeln!(out, "| Validators | {} |", sim.validators)?;
+        match sim.consensus {
+            Consensus::Aura => writeln!(out, "| Consensus | Aura, one seal per block |")?,
+            Consensus::Hotstuff => writeln!(
+                out,
+                "| Consensus | HotStuff, quorum {} of {} ({} byte certificates) |",
+                sim.quorum,
+                sim.validators,
+                sim.quorum * sim.signature_bytes
+            )?,
+        }
+        writeln!(
+            out,
+            "| Blocks | {} s slots, {} s weight, {} byte limit, {} byte transactions |",
+            sim.block_time_secs, sim.block_weight_secs, sim.max_block_bytes, sim.tx_bytes
+        )?;
+        writeln!(
+            out,
+            "| Load | {:.1} TPS offered for {} s, {} ms gossip, mempool {} (seed {}) |",
+            sim.offered_tps, sim.duration_secs, sim.gossip_ms, sim.mempool_limit, sim.seed
+        )?;
+        writeln!(
+            out,
+            "| Transactions | {} submitted, {} included, {} dropped, {} pending |",
+            sim.submitted, sim.included, sim.dropped, sim.pending
+        )?;
+        writeln!(out, "| Blocks produced | {} ({} full, {} orphaned) |", sim.blocks, sim.full_blocks, sim.orphaned_blocks)?;
+        let latency = &sim.inclusion_latency;
+        writeln!(
+            out,
+            "| Inclusion latency | p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms, max {:.0} ms |",
+            latency.p50_ms, latency.p95_ms, latency.p99_ms, latency.max_ms
+        )?;
+        let latency = &sim.finality_latency;
+        writeln!(
+            out,
+            "| Finality latency | p50 {:.0} ms, p95 {:.0} ms, p99 {:.0} ms, max {:.0} ms |",
+            latency.p50_ms, latency.p95_ms, latency.p99_ms, latency.max_ms
+        )?;
+        writeln!(
+            out,
+            "| Consensus signatures | {:.0} per block, {:.2} ms and {} bytes each |",
+            sim.signatures_per_block(),
+            sim.sign_ms,
+            sim.signature_bytes
+        )?;
+        writeln!(out, "| Consensus verifications | {:.0} per block, network-wide |", sim.verifications_per_block())?;
+        let (min, mean, max) = sim.utilization();
+        writeln!(
+            out,
+            "| Validator load | {:.1}% min, {:.1}% mean, {:.1}% max busy verifying and signing |",
+            min * 100.0,
+            mean * 100.0,
+            max * 100.0
+        )?;
+        writeln!(out)?;
+        writeln!(out, "| Time (s) | Pending | Included |")?;
+        writeln!(out, "|---:|---:|---:|")?;
+        for sample in sim.backlog.iter().step_by((sim.backlog.len() / 10).max(1)) {
+            writeln!(out, "| {:.1} | {} | {} |", sample.time_secs, sample.pending, sample.included)?;
+        }
+        writeln!(out)?;
+        if let Some(aura) = &report.simulation_baseline {
+            write_consensus_comparison(out, aura, sim)?;
+        }
+    }
+    if let Some(real) = &report.real_tps {
+        writeln!(out, "## Real TPS")?;
+        writeln!(out)?;
+        writeln!(out, "| | |")?;
+        writeln!(out, "|---|---|")?;
+        writeln!(out, "| Submitted | {} ({} ok, {} failed) |", real.submitted, real.successful, real.failed)?;
+        writeln!(out, "| Blocks | #{} → #{} ({} produced) |", real.start_block, real.end_block, real.blocks_produced)?;
+        writeln!(out, "| Submission TPS | {:.2} over {:.2} s |", real.submission_tps, real.submission_secs)?;
+        writeln!(out, "| Effective TPS | {:.2} over {:.2} s |", real.effective_tps, real.total_secs)?;
+        if let Some(direct) = &real.direct {
+            writeln!(
+                out,
+                "| Direct submission | {} from `{}`, {} bytes each, signed in {:.2} s |",
+                direct.scheme, direct.signer, direct.extrinsic_bytes, direct.sign_secs
+            )?;
+            writeln!(
+                out,
+                "| Node answers | {} accepted, {} rejected, {} failed |",
+                direct.accepted, direct.rejected, direct.failed
+            )?;
+        }
+        writeln!(out)?;
+        if let Some(direct) = real.direct.as_ref().filter(|d| !d.reasons.is_empty()) {
+            writeln!(out, "| Rejections | Reason |")?;
+            writeln!(out, "|---:|---|")?;
+            for reason in &direct.reasons {
+                writeln!(out, "| {} | {} |", reason.count, reason.reason.replace('|', "\\|"))?;
+            }
+            writeln!(out)?;
+        }
+        if let Some(inclusion) = &real.inclusion {
+            writeln!(out, "### Transaction lifecycle")?;
+            writeln!(out)?;
+            writeln!(
+                out,
+                "{} tracked: {} ready, {} in block, {} finalized.",
+                inclusion.tracked, inclusion.ready, inclusion.in_block, inclusion.finalized
+            )?;
+            writeln!(out)?;
+            writeln!(out, "| Stage | Count | p50 (s) | p95 (s) | p99 (s) | Max (s) |")?;
+            writeln!(out, "|---|---:|---:|---:|---:|---:|")?;
+            for (stage, row) in [
+                ("Submit → ready", &inclusion.submit_to_ready),
+                ("Submit → in block", &inclusion.submit_to_in_block),
+                ("Submit → finalized", &inclusion.submit_to_finalized),
+                ("In block → finalized", &inclusion.in_block_to_finalized),
+            ]
+            .into_iter()
+            .filter(|(_, row)| row.count > 0)
+            {
+                writeln!(
+                    out,
+                    "| {} | {} | {:.2} | {:.2} | {:.2} | {:.2} |",
+                    stage,
+                    row.count,
+                    row.p50_ms / 1000.0,
+                    row.p95_ms / 1000.0,
+                    row.p99_ms / 1000.0,
+                    row.max_ms / 1000.0
+                )?;
+            }
+            writeln!(out)?;
+            if !inclusion.missing.is_empty() {
+                writeln!(out, "Never landed in a block:")?;
+                writeln!(out)?;
+                for missing in &inclusion.missing {
+                    writeln!(out, "- `{}` ({})", missing.hash, missing.status)?;
+                }
+                writeln!(out)?;
+            }
+            if !inclusion.skipped_blocks.is_empty() {
+                writeln!(out, "Blocks that could not be scanned:")?;
+                writeln!(out)?;
+                for skipped in &inclusion.skipped_blocks {
+                    writeln!(out, "- #{} ({})", skipped.number, skipped.error)?;
+                }
+                writeln!(out)?;
+            }
+        }
+        if let Some(curve) = &real.load {
+            writeln!(out, "### Offered vs included load")?;
+            writeln!(out)?;
+            writeln!(
+                out,
+                "`{}` over {:.0} s, {:.1} s windows, {:.1} s blocks. {}",
+                curve.profile,
+                curve.duration_secs,
+                curve.window_secs,
+                curve.block_secs,
+                curve.summary()
+            )?;
+            writeln!(out)?;
+            writeln!(out, "| Window (s) | Offered TPS | Accepted TPS | Included TPS | Landed | p50 inclusion (s) |")?;
+            writeln!(out, "|---|---:|---:|---:|---:|---:|")?;
+            for w in &curve.windows {
+                writeln!(
+                    out,
+                    "| {:.1}–{:.1} | {:.1} | {:.1} | {:.1} | {:.0}% | {:.2} |",
+                    w.start_secs,
+                    w.end_secs,
+                    w.offered_tps,
+                    w.accepted_tps,
+                    w.included_tps,
+                    w.landed * 100.0,
+                    w.inclusion_p50_ms / 1000.0
+                )?;
+            }
+            writeln!(out)?;
+        }
+    }
+    if report.config.mode != "local" && report.network.is_none() && report.real_tps.is_none() {
+        writeln!(out, "## Network")?;
+        writeln!(out)?;
+        writeln!(out, "No results: validators or faucet were unreachable.")?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// The same simulated load under two consensus protocols
+fn write_consensus_comparison(out: &mut String, aura: &SimulationMetrics, sim: &SimulationMetrics) -> std::fmt::Result {
+    writeln!(out, "### Compared with {}", aura.consensus)?;
+    writeln!(out)?;
+    writeln!(out, "| | {} | {} |", aura.consensus, sim.consensus)?;
+    writeln!(out, "|---|---:|---:|")?;
+    for (label, values) in simulate::compare(&[aura, sim]) {
+        writeln!(out, "| {} | {} |", label, values.join(" | "))?;
+    }
+    writeln!(out)
+}
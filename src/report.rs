@@ -0,0 +1,514 @@
+//! Machine-readable benchmark results
+//!
+//! These types are the stable, versioned output schema written by
+//! `--output json|csv`. Any change that renames or removes a field must bump
+//! [`SCHEMA_VERSION`]; adding optional fields does not.
+//!
+//! JSON carries the whole [`BenchmarkReport`]. CSV is flat: one row per
+//! [`StrategyRow`] for local runs, or a single metrics row for the network
+//! modes.
+
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub use crate::environment::{BuildInfo, Environment};
+use crate::assertions::AssertionResult;
+use crate::capacity::CapacityProjection;
+use crate::extrinsic::BlockExtrinsics;
+use crate::heads::{HeadStats, HeadTimeline};
+use crate::inclusion::{InclusionStats, TxTrace};
+use crate::latency::LatencyHistogram;
+use crate::load::LoadCurve;
+use crate::scalability::Scalability;
+use crate::scenario::Suite;
+use crate::simulate::SimulationMetrics;
+use crate::stats::Stats;
+use crate::submit::DirectSubmission;
+use crate::sweep::SweepSpec;
+use crate::torus::MeshStats;
+
+/// Version of the result schema; bumped on breaking changes
+///
+/// v2: `theoretical_max_tps` is the capacity projection (0 when none is
+/// made) instead of validators × local cores × 4.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Everything one invocation of the benchmark produced
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BenchmarkReport {
+    pub schema_version: u32,
+    pub tool_version: String,
+    /// Seconds since the Unix epoch when the report was created
+    pub generated_at: u64,
+    pub config: RunConfig,
+    /// Host and build fingerprint; compare results across machines only with this in view
+    pub environment: Environment,
+    /// Per-scheme keygen/sign/verify headline numbers
+    #[serde(default)]
+    pub schemes: Vec<SchemeRow>,
+    /// One row per measured verification strategy
+    #[serde(default)]
+    pub results: Vec<StrategyRow>,
+    #[serde(default)]
+    pub network: Option<NetworkMetrics>,
+    #[serde(default)]
+    pub real_tps: Option<RealTpsMetrics>,
+    /// Outcome of any `--assert-*` thresholds
+    #[serde(default)]
+    pub assertions: Vec<AssertionResult>,
+    /// Projected network TPS per scheme row, for `--validator-spec`
+    #[serde(default)]
+    pub capacity: Vec<CapacityProjection>,
+    /// `--simulate` details beyond the `network` metrics
+    #[serde(default)]
+    pub simulation: Option<SimulationMetrics>,
+    /// The same simulated load under Aura, when `simulation` ran another consensus
+    #[serde(default)]
+    pub simulation_baseline: Option<SimulationMetrics>,
+}
+
+/// The options the run was started with
+///
+/// `schemes` and `tx_counts` list everything that ran; the other fields are
+/// the command-line values, which scenario suites may override.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RunConfig {
+    /// "local", "network", "real-tps" or "simulate"
+    pub mode: String,
+    pub schemes: Vec<String>,
+    pub tx_counts: Vec<usize>,
+    pub segments: usize,
+    pub keypairs: usize,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub pool_threads: usize,
+    pub torus: Option<[usize; 3]>,
+    pub both_paths: bool,
+    #[serde(default)]
+    pub validators: Vec<String>,
+    /// Scenario file or bundled scenario name, for `--scenario`, `--quick` and `--full`
+    #[serde(default)]
+    pub scenario: Option<String>,
+    /// The suites that ran, as defined in the scenario
+    #[serde(default)]
+    pub suites: Vec<Suite>,
+    /// Torus sweep spec, `--sweep` or its `pow2:<segments>` default
+    #[serde(default)]
+    pub sweep: Option<SweepSpec>,
+    /// Every segment count the torus sweep ran, across all suites
+    #[serde(default)]
+    pub sweep_segments: Vec<usize>,
+    /// `--thread-sweep`, if given
+    #[serde(default)]
+    pub thread_sweep: Option<SweepSpec>,
+}
+
+/// Keygen, signing and verification headline numbers for one scheme and tx count
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchemeRow {
+    /// Scenario suite the row belongs to
+    #[serde(default)]
+    pub suite: Option<String>,
+    pub scheme: String,
+    pub scheme_name: String,
+    pub family: String,
+    /// NIST category, 0 for classical schemes
+    pub nist_level: u8,
+    pub public_key_bytes: usize,
+    pub signature_bytes: usize,
+    /// "detached" or "attached"
+    pub path: String,
+    pub tx_count: usize,
+    #[serde(default)]
+    pub payload_bytes: usize,
+    pub keygen_seq_per_sec: f64,
+    pub keygen_par_per_sec: f64,
+    pub sign_seq_per_sec: f64,
+    pub sign_par_per_sec: f64,
+    pub sequential_tps: f64,
+    pub best_segments: usize,
+    pub best_parallel_tps: f64,
+    /// Amdahl and USL fits over the thread-count sweep, if one ran
+    #[serde(default)]
+    pub scalability: Option<Scalability>,
+}
+
+/// One measured verification strategy
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StrategyRow {
+    /// Scenario suite the row belongs to
+    #[serde(default)]
+    pub suite: Option<String>,
+    pub scheme: String,
+    pub path: String,
+    pub tx_count: usize,
+    /// Transaction payload size before hashing
+    #[serde(default)]
+    pub payload_bytes: usize,
+    /// "baseline", "sweep", "torus", "strategy" or "threads"
+    pub kind: String,
+    pub strategy: String,
+    pub segments: Option<usize>,
+    /// Rayon pool size, for thread-sweep rows
+    #[serde(default)]
+    pub threads: Option<usize>,
+    pub verified: usize,
+    pub total: usize,
+    pub tps: ThroughputStats,
+    /// Mean TPS relative to the sequential baseline
+    pub speedup: f64,
+    pub latency: LatencyRow,
+    pub mesh: Option<MeshRow>,
+}
+
+impl StrategyRow {
+    /// Heading for the group of rows measured on the same transaction set
+    pub fn group_label(&self) -> String {
+        let suite = self.suite.as_ref().map(|s| format!("[{}] ", s)).unwrap_or_default();
+        format!(
+            "{}{} ({}), {} transactions of {} bytes",
+            suite, self.scheme, self.path, self.tx_count, self.payload_bytes
+        )
+    }
+}
+
+/// TPS across repeated runs
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThroughputStats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Half-width of the 95% confidence interval for the mean
+    pub ci95: f64,
+    pub samples: Vec<f64>,
+    /// Indices into `samples` flagged as outliers
+    pub outliers: Vec<usize>,
+}
+
+impl From<&Stats> for ThroughputStats {
+    fn from(stats: &Stats) -> Self {
+        Self {
+            mean: stats.mean,
+            median: stats.median,
+            stddev: stats.stddev,
+            min: stats.min,
+            max: stats.max,
+            ci95: stats.ci95,
+            samples: stats.samples.clone(),
+            outliers: stats.outliers.clone(),
+        }
+    }
+}
+
+/// Buckets in [`LatencyRow::histogram`]
+const HISTOGRAM_BUCKETS: usize = 24;
+
+/// Per-signature verify latency, in milliseconds
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LatencyRow {
+    pub count: u64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    /// Equal-width buckets from the fastest to the slowest verify
+    #[serde(default)]
+    pub histogram: Vec<HistogramBucket>,
+}
+
+/// One latency histogram bar
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistogramBucket {
+    pub lower_ms: f64,
+    pub upper_ms: f64,
+    pub count: u64,
+}
+
+impl From<&LatencyHistogram> for LatencyRow {
+    fn from(histogram: &LatencyHistogram) -> Self {
+        let ms = |d: std::time::Duration| d.as_secs_f64() * 1000.0;
+        let latency = histogram.summary();
+        Self {
+            count: latency.count,
+            mean_ms: ms(latency.mean),
+            p50_ms: ms(latency.p50),
+            p95_ms: ms(latency.p95),
+            p99_ms: ms(latency.p99),
+            max_ms: ms(latency.max),
+            histogram: histogram
+                .buckets(HISTOGRAM_BUCKETS)
+                .into_iter()
+                .map(|b| HistogramBucket {
+                    lower_ms: ms(b.lower),
+                    upper_ms: ms(b.upper),
+                    count: b.count,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Torus mesh load distribution for one run
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MeshRow {
+    pub dims: [usize; 3],
+    pub load: Vec<usize>,
+    pub steals: Vec<usize>,
+    pub total_steals: usize,
+    pub imbalance: f64,
+}
+
+impl From<&MeshStats> for MeshRow {
+    fn from(stats: &MeshStats) -> Self {
+        Self {
+            dims: stats.dims,
+            load: stats.load.clone(),
+            steals: stats.steals.clone(),
+            total_steals: stats.total_steals(),
+            imbalance: stats.imbalance(),
+        }
+    }
+}
+
+/// A validator seen during a network run
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatorStatus {
+    pub url: String,
+    pub name: String,
+    pub online: bool,
+    pub peers: Option<u32>,
+    pub error: Option<String>,
+}
+
+/// `--network` block production metrics
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NetworkMetrics {
+    pub validators: Vec<ValidatorStatus>,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub blocks_produced: u64,
+    pub elapsed_secs: f64,
+    pub block_time_secs: f64,
+    pub blocks_per_sec: f64,
+    /// Assumes a fixed number of transactions per block for `--network`; simulated for `--simulate`
+    pub estimated_tps: f64,
+    /// Transactions per second counted in the observed blocks, `None` when they were not fetched
+    #[serde(default)]
+    pub measured_tps: Option<f64>,
+    /// Signed extrinsics in the observed blocks
+    #[serde(default)]
+    pub transactions: u64,
+    /// Bare extrinsics (inherents) in the observed blocks
+    #[serde(default)]
+    pub inherents: u64,
+    /// Extrinsic counts for each block after `start_block`, up to `end_block`
+    #[serde(default)]
+    pub blocks: Vec<BlockExtrinsics>,
+    /// Block time, stall and finality statistics from the head subscriptions
+    #[serde(default)]
+    pub heads: Option<HeadStats>,
+    /// Every new and finalized head seen, `None` when the WebSocket subscription failed
+    #[serde(default)]
+    pub timeline: Option<HeadTimeline>,
+    /// Projected capacity from measured verify rates and `--validator-spec`, 0 when not projected
+    pub theoretical_max_tps: f64,
+}
+
+/// `--real-tps` transaction metrics, through the faucet or submitted with `--direct`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RealTpsMetrics {
+    pub validators: Vec<ValidatorStatus>,
+    pub submitted: usize,
+    pub successful: usize,
+    pub failed: usize,
+    pub submission_secs: f64,
+    pub total_secs: f64,
+    pub start_block: u64,
+    pub end_block: u64,
+    pub blocks_produced: u64,
+    pub submission_tps: f64,
+    pub effective_tps: f64,
+    pub tx_hashes: Vec<String>,
+    /// Signing details and node answers for `--direct`; `failed` then counts rejected and unanswered
+    #[serde(default)]
+    pub direct: Option<DirectSubmission>,
+    /// Submit to ready, in block and finalized latencies; `None` when nothing could be tracked
+    #[serde(default)]
+    pub inclusion: Option<InclusionStats>,
+    /// Every tracked transaction
+    #[serde(default)]
+    pub traces: Vec<TxTrace>,
+    /// Offered against included load, for `--load` runs
+    #[serde(default)]
+    pub load: Option<LoadCurve>,
+}
+
+impl BenchmarkReport {
+    pub fn new(config: RunConfig) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+            generated_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            config,
+            environment: Environment::detect(),
+            schemes: Vec::new(),
+            results: Vec::new(),
+            network: None,
+            real_tps: None,
+            assertions: Vec::new(),
+            capacity: Vec::new(),
+            simulation: None,
+            simulation_baseline: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("report serializes")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let report: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if report.schema_version > SCHEMA_VERSION {
+            return Err(format!(
+                "report schema v{} is newer than supported v{}",
+                report.schema_version, SCHEMA_VERSION
+            ));
+        }
+        Ok(report)
+    }
+
+    /// Flat CSV: strategy rows for local runs, one metrics row otherwise
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+        if let Some(network) = &self.network {
+            push_csv_row(
+                &mut out,
+                &["schema_version", "mode", "validators_online", "start_block", "end_block", "blocks_produced",
+                  "elapsed_secs", "block_time_secs", "blocks_per_sec", "estimated_tps", "theoretical_max_tps",
+                  "measured_tps", "transactions", "inherents"],
+            );
+            push_csv_row(
+                &mut out,
+                &[
+                    &self.schema_version.to_string(),
+                    &self.config.mode,
+                    &network.validators.iter().filter(|v| v.online).count().to_string(),
+                    &network.start_block.to_string(),
+                    &network.end_block.to_string(),
+                    &network.blocks_produced.to_string(),
+                    &network.elapsed_secs.to_string(),
+                    &network.block_time_secs.to_string(),
+                    &network.blocks_per_sec.to_string(),
+                    &network.estimated_tps.to_string(),
+                    &network.theoretical_max_tps.to_string(),
+                    &network.measured_tps.map_or(String::new(), |t| t.to_string()),
+                    &network.transactions.to_string(),
+                    &network.inherents.to_string(),
+                ],
+            );
+            return out;
+        }
+        if let Some(real) = &self.real_tps {
+            push_csv_row(
+                &mut out,
+                &["schema_version", "mode", "submitted", "successful", "failed", "submission_secs", "total_secs",
+                  "start_block", "end_block", "blocks_produced", "submission_tps", "effective_tps", "rejected",
+                  "in_block", "finalized", "in_block_p50_ms", "finalized_p50_ms", "load_profile", "knee_tps",
+                  "peak_included_tps"],
+            );
+            push_csv_row(
+                &mut out,
+                &[
+                    &self.schema_version.to_string(),
+                    &self.config.mode,
+                    &real.submitted.to_string(),
+                    &real.successful.to_string(),
+                    &real.failed.to_string(),
+                    &real.submission_secs.to_string(),
+                    &real.total_secs.to_string(),
+                    &real.start_block.to_string(),
+                    &real.end_block.to_string(),
+                    &real.blocks_produced.to_string(),
+                    &real.submission_tps.to_string(),
+                    &real.effective_tps.to_string(),
+                    &real.direct.as_ref().map_or(String::new(), |d| d.rejected.to_string()),
+                    &real.inclusion.as_ref().map_or(String::new(), |i| i.in_block.to_string()),
+                    &real.inclusion.as_ref().map_or(String::new(), |i| i.finalized.to_string()),
+                    &real.inclusion.as_ref().map_or(String::new(), |i| i.submit_to_in_block.p50_ms.to_string()),
+                    &real.inclusion.as_ref().map_or(String::new(), |i| i.submit_to_finalized.p50_ms.to_string()),
+                    real.load.as_ref().map_or("", |l| l.profile.as_str()),
+                    &real.load.as_ref().and_then(|l| l.knee_tps).map_or(String::new(), |k| k.to_string()),
+                    &real.load.as_ref().map_or(String::new(), |l| l.peak_included_tps.to_string()),
+                ],
+            );
+            return out;
+        }
+
+        push_csv_row(
+            &mut out,
+            &["schema_version", "scheme", "path", "tx_count", "kind", "strategy", "segments", "verified", "total",
+              "tps_mean", "tps_median", "tps_stddev", "tps_min", "tps_max", "tps_ci95", "runs", "outliers", "speedup",
+              "latency_p50_ms", "latency_p95_ms", "latency_p99_ms", "latency_max_ms", "mesh_steals", "mesh_imbalance",
+              "cpu_model", "logical_cores", "git_commit", "suite", "payload_bytes", "threads"],
+        );
+        for row in &self.results {
+            push_csv_row(
+                &mut out,
+                &[
+                    &self.schema_version.to_string(),
+                    &row.scheme,
+                    &row.path,
+                    &row.tx_count.to_string(),
+                    &row.kind,
+                    &row.strategy,
+                    &row.segments.map(|s| s.to_string()).unwrap_or_default(),
+                    &row.verified.to_string(),
+                    &row.total.to_string(),
+                    &row.tps.mean.to_string(),
+                    &row.tps.median.to_string(),
+                    &row.tps.stddev.to_string(),
+                    &row.tps.min.to_string(),
+                    &row.tps.max.to_string(),
+                    &row.tps.ci95.to_string(),
+                    &row.tps.samples.len().to_string(),
+                    &row.tps.outliers.len().to_string(),
+                    &row.speedup.to_string(),
+                    &row.latency.p50_ms.to_string(),
+                    &row.latency.p95_ms.to_string(),
+                    &row.latency.p99_ms.to_string(),
+                    &row.latency.max_ms.to_string(),
+                    &row.mesh.as_ref().map(|m| m.total_steals.to_string()).unwrap_or_default(),
+                    &row.mesh.as_ref().map(|m| m.imbalance.to_string()).unwrap_or_default(),
+                    self.environment.cpu_model.as_deref().unwrap_or_default(),
+                    &self.environment.logical_cores.to_string(),
+                    &self.environment.build.git_commit,
+                    row.suite.as_deref().unwrap_or_default(),
+                    &row.payload_bytes.to_string(),
+                    &row.threads.map(|t| t.to_string()).unwrap_or_default(),
+                ],
+            );
+        }
+        out
+    }
+}
+
+fn push_csv_row(out: &mut String, fields: &[&str]) {
+    let escaped: Vec<String> = fields
+        .iter()
+        .map(|f| {
+            if f.contains([',', '"', '\n']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.to_string()
+            }
+        })
+        .collect();
+    out.push_str(&escaped.join(","));
+    out.push('\n');
+}
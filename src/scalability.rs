@@ -0,0 +1,229 @@
+//! Scalability models
+//!
+//! A thread-count sweep measures throughput X(N) at N worker threads. Two
+//! models are fitted to the relative capacity C(N) = X(N) / X(1):
+//!
+//! - Amdahl's law, C(N) = N / (1 + σ(N − 1)): a serial fraction σ caps the
+//!   speedup at 1/σ however many cores are added.
+//! - The Universal Scalability Law, C(N) = N / (1 + σ(N − 1) + κN(N − 1)):
+//!   contention σ plus a coherency cost κ that makes throughput fall again
+//!   past a peak at N* = √((1 − σ) / κ).
+//!
+//! Both are linear in their coefficients after rewriting as
+//! N / C(N) − 1 = σ(N − 1) + κN(N − 1), so they are fitted by least squares
+//! with no intercept.
+
+use serde::{Deserialize, Serialize};
+
+/// Throughput measured at one thread count
+#[derive(Clone, Copy, Debug)]
+pub struct ScalingPoint {
+    pub threads: usize,
+    pub tps: f64,
+}
+
+/// Both fits for one thread-count sweep
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Scalability {
+    /// Thread counts measured, in order
+    pub threads: Vec<usize>,
+    /// Throughput at one thread, the reference for relative capacity
+    pub single_thread_tps: f64,
+    pub amdahl: Option<AmdahlFit>,
+    pub usl: Option<UslFit>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AmdahlFit {
+    /// σ: share of the work that does not parallelize (0-1)
+    pub serial_fraction: f64,
+    /// 1/σ, `None` when the fit found no serial fraction
+    pub max_speedup: Option<f64>,
+    pub max_tps: Option<f64>,
+    /// Goodness of fit on throughput (1.0 = exact)
+    pub r_squared: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UslFit {
+    /// σ: contention (queueing on shared resources)
+    pub contention: f64,
+    /// κ: coherency (cost of keeping shared state consistent)
+    pub coherency: f64,
+    /// N* where throughput peaks, `None` without a coherency cost
+    pub peak_threads: Option<f64>,
+    pub peak_tps: Option<f64>,
+    /// Goodness of fit on throughput (1.0 = exact)
+    pub r_squared: f64,
+}
+
+impl AmdahlFit {
+    pub fn speedup(&self, threads: f64) -> f64 {
+        threads / (1.0 + self.serial_fraction * (threads - 1.0))
+    }
+}
+
+impl UslFit {
+    pub fn speedup(&self, threads: f64) -> f64 {
+        threads / (1.0 + self.contention * (threads - 1.0) + self.coherency * threads * (threads - 1.0))
+    }
+}
+
+impl Scalability {
+    /// Fit both models; `None` without a one-thread point to normalize against
+    pub fn fit(points: &[ScalingPoint]) -> Option<Self> {
+        let single = points.iter().find(|p| p.threads == 1)?.tps;
+        if single <= 0.0 {
+            return None;
+        }
+        // (N − 1, N(N − 1), N / C(N) − 1) per multi-threaded point
+        let terms: Vec<(f64, f64, f64)> = points
+            .iter()
+            .filter(|p| p.threads > 1 && p.tps > 0.0)
+            .map(|p| {
+                let n = p.threads as f64;
+                (n - 1.0, n * (n - 1.0), n / (p.tps / single) - 1.0)
+            })
+            .collect();
+
+        let amdahl = fit_amdahl(&terms).map(|sigma| {
+            let mut fit = AmdahlFit {
+                serial_fraction: sigma,
+                max_speedup: (sigma > 0.0).then(|| 1.0 / sigma),
+                max_tps: (sigma > 0.0).then(|| single / sigma),
+                r_squared: 0.0,
+            };
+            fit.r_squared = r_squared(points, |n| single * fit.speedup(n));
+            fit
+        });
+        let usl = fit_usl(&terms).map(|(sigma, kappa)| {
+            let mut fit = UslFit {
+                contention: sigma,
+                coherency: kappa,
+                peak_threads: (kappa > 0.0).then(|| ((1.0 - sigma) / kappa).sqrt()),
+                peak_tps: None,
+                r_squared: 0.0,
+            };
+            fit.peak_tps = fit.peak_threads.map(|n| single * fit.speedup(n));
+            fit.r_squared = r_squared(points, |n| single * fit.speedup(n));
+            fit
+        });
+
+        Some(Self {
+            threads: points.iter().map(|p| p.threads).collect(),
+            single_thread_tps: single,
+            amdahl,
+            usl,
+        })
+    }
+}
+
+/// σ minimizing Σ(σx₁ − y)², clamped to 0-1
+fn fit_amdahl(terms: &[(f64, f64, f64)]) -> Option<f64> {
+    let sxx: f64 = terms.iter().map(|t| t.0 * t.0).sum();
+    if sxx == 0.0 {
+        return None;
+    }
+    let sxy: f64 = terms.iter().map(|t| t.0 * t.2).sum();
+    Some((sxy / sxx).clamp(0.0, 1.0))
+}
+
+/// (σ, κ) minimizing Σ(σx₁ + κx₂ − y)², both kept non-negative
+fn fit_usl(terms: &[(f64, f64, f64)]) -> Option<(f64, f64)> {
+    // Two coefficients need at least two distinct thread counts above one
+    if terms.iter().all(|t| t.0 == terms[0].0) {
+        return None;
+    }
+    let (mut s11, mut s12, mut s22, mut s1y, mut s2y) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for &(x1, x2, y) in terms {
+        s11 += x1 * x1;
+        s12 += x1 * x2;
+        s22 += x2 * x2;
+        s1y += x1 * y;
+        s2y += x2 * y;
+    }
+    let det = s11 * s22 - s12 * s12;
+    if det.abs() < f64::EPSILON * s11 * s22 {
+        return None;
+    }
+    let sigma = (s1y * s22 - s2y * s12) / det;
+    let kappa = (s2y * s11 - s1y * s12) / det;
+
+    // A negative coefficient has no physical meaning; refit with it pinned at zero
+    Some(if kappa < 0.0 {
+        (fit_amdahl(terms)?, 0.0)
+    } else if sigma < 0.0 {
+        (0.0, (s2y / s22).max(0.0))
+    } else {
+        (sigma.min(1.0), kappa)
+    })
+}
+
+/// Coefficient of determination of `predict` against the measured throughput
+fn r_squared(points: &[ScalingPoint], predict: impl Fn(f64) -> f64) -> f64 {
+    let mean = points.iter().map(|p| p.tps).sum::<f64>() / points.len() as f64;
+    let ss_tot: f64 = points.iter().map(|p| (p.tps - mean).powi(2)).sum();
+    let ss_res: f64 = points.iter().map(|p| (p.tps - predict(p.threads as f64)).powi(2)).sum();
+    if ss_tot == 0.0 {
+        return if ss_res == 0.0 { 1.0 } else { 0.0 };
+    }
+    1.0 - ss_res / ss_tot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweep(speedup: impl Fn(f64) -> f64) -> Vec<ScalingPoint> {
+        [1, 2, 4, 8, 16, 32, 64]
+            .into_iter()
+            .map(|threads| ScalingPoint { threads, tps: 100.0 * speedup(threads as f64) })
+            .collect()
+    }
+
+    #[test]
+    fn recovers_amdahl_serial_fraction() {
+        let sigma = 0.05;
+        let fit = Scalability::fit(&sweep(|n| n / (1.0 + sigma * (n - 1.0)))).unwrap();
+        let amdahl = fit.amdahl.unwrap();
+        assert!((amdahl.serial_fraction - sigma).abs() < 1e-9);
+        assert!((amdahl.max_speedup.unwrap() - 20.0).abs() < 1e-6);
+        assert!((amdahl.max_tps.unwrap() - 2000.0).abs() < 1e-4);
+        assert!(amdahl.r_squared > 0.999_999);
+        // Pure Amdahl data has no coherency cost for the USL to find
+        let usl = fit.usl.unwrap();
+        assert!((usl.contention - sigma).abs() < 1e-6);
+        assert!(usl.coherency.abs() < 1e-9);
+    }
+
+    #[test]
+    fn recovers_usl_coefficients_and_peak() {
+        let (sigma, kappa) = (0.02, 0.001);
+        let fit = Scalability::fit(&sweep(|n| n / (1.0 + sigma * (n - 1.0) + kappa * n * (n - 1.0)))).unwrap();
+        let usl = fit.usl.unwrap();
+        assert!((usl.contention - sigma).abs() < 1e-9);
+        assert!((usl.coherency - kappa).abs() < 1e-9);
+        let peak = ((1.0 - sigma) / kappa).sqrt();
+        assert!((usl.peak_threads.unwrap() - peak).abs() < 1e-6);
+        assert!(usl.r_squared > 0.999_999);
+        // Amdahl can't bend back down, so it fits retrograde data worse
+        assert!(fit.amdahl.unwrap().r_squared < usl.r_squared);
+    }
+
+    #[test]
+    fn linear_scaling_has_no_limit() {
+        let fit = Scalability::fit(&sweep(|n| n)).unwrap();
+        let amdahl = fit.amdahl.unwrap();
+        assert_eq!(amdahl.serial_fraction, 0.0);
+        assert!(amdahl.max_speedup.is_none());
+        assert!(fit.usl.unwrap().peak_threads.is_none());
+    }
+
+    #[test]
+    fn needs_a_single_thread_point() {
+        let points = [ScalingPoint { threads: 2, tps: 10.0 }, ScalingPoint { threads: 4, tps: 20.0 }];
+        assert!(Scalability::fit(&points).is_none());
+        let one_extra = [ScalingPoint { threads: 1, tps: 10.0 }, ScalingPoint { threads: 4, tps: 30.0 }];
+        assert!(Scalability::fit(&one_extra).unwrap().usl.is_none());
+    }
+}
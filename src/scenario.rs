@@ -0,0 +1,214 @@
+//! Scenario files
+//!
+//! A scenario is a TOML file of named `[[suite]]` tables, each describing one
+//! benchmark run: schemes, transaction counts, the segment sweep, keypairs,
+//! payload sizes, iterations and assertions. Every field is optional and
+//! falls back to the command-line value, so a suite only states what it
+//! changes. `--quick` and `--full` are the bundled `quick` and `full`
+//! scenarios in `scenarios/`.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::assertions::Assertions;
+use crate::scheme::Scheme;
+use crate::sweep::SweepSpec;
+
+/// Scenarios compiled into the binary, by name
+pub const BUNDLED: &[(&str, &str)] = &[
+    ("quick", include_str!("../scenarios/quick.toml")),
+    ("full", include_str!("../scenarios/full.toml")),
+];
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    #[serde(rename = "suite")]
+    pub suites: Vec<Suite>,
+}
+
+/// One named benchmark run; unset fields fall back to the command line
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Suite {
+    pub name: String,
+    pub description: Option<String>,
+    /// Scheme ids, e.g. "shake-128f"
+    pub schemes: Option<Vec<String>>,
+    pub tx_counts: Option<Vec<usize>>,
+    /// Segment counts for the torus sweep: a list, or a spec like "pow2:512"
+    pub sweep: Option<SweepSpec>,
+    /// Thread counts for the rayon scaling sweep, e.g. "1..8"
+    pub thread_sweep: Option<SweepSpec>,
+    /// Segment count for the strategy comparison
+    pub segments: Option<usize>,
+    pub keypairs: Option<usize>,
+    /// Transaction payload sizes in bytes (padded before hashing and signing)
+    pub payload_bytes: Option<Vec<usize>>,
+    pub iterations: Option<usize>,
+    pub warmup: Option<usize>,
+    pub both_paths: Option<bool>,
+    pub assertions: Assertions,
+}
+
+impl Scenario {
+    /// Load a scenario from a file, or a bundled one by name
+    pub fn load(name_or_path: &str) -> Result<Self, String> {
+        if Path::new(name_or_path).is_file() {
+            let toml = std::fs::read_to_string(name_or_path).map_err(|e| format!("{}: {}", name_or_path, e))?;
+            return Self::parse(&toml).map_err(|e| format!("{}: {}", name_or_path, e));
+        }
+        match BUNDLED.iter().find(|(name, _)| *name == name_or_path) {
+            Some((name, toml)) => Self::parse(toml).map_err(|e| format!("bundled scenario '{}': {}", name, e)),
+            None => {
+                let bundled: Vec<&str> = BUNDLED.iter().map(|(name, _)| *name).collect();
+                Err(format!(
+                    "no scenario file '{}' and no bundled scenario of that name (bundled: {})",
+                    name_or_path,
+                    bundled.join(", ")
+                ))
+            }
+        }
+    }
+
+    pub fn parse(toml: &str) -> Result<Self, String> {
+        let scenario: Self = toml::from_str(toml).map_err(|e| e.to_string())?;
+        if scenario.suites.is_empty() {
+            return Err("scenario has no [[suite]] tables".to_string());
+        }
+        for (i, suite) in scenario.suites.iter().enumerate() {
+            if suite.name.is_empty() {
+                return Err(format!("suite #{} has no name", i + 1));
+            }
+            if scenario.suites[..i].iter().any(|s| s.name == suite.name) {
+                return Err(format!("duplicate suite name '{}'", suite.name));
+            }
+            suite.validate().map_err(|e| format!("suite '{}': {}", suite.name, e))?;
+        }
+        Ok(scenario)
+    }
+
+    /// The suites to run: all of them, or only those named in `only`
+    pub fn select(&self, only: &[String]) -> Result<Vec<&Suite>, String> {
+        if only.is_empty() {
+            return Ok(self.suites.iter().collect());
+        }
+        only.iter()
+            .map(|name| {
+                self.suites.iter().find(|s| &s.name == name).ok_or_else(|| {
+                    let names: Vec<&str> = self.suites.iter().map(|s| s.name.as_str()).collect();
+                    format!("no suite '{}' (suites: {})", name, names.join(", "))
+                })
+            })
+            .collect()
+    }
+}
+
+impl Suite {
+    /// The suite's schemes, if it sets any
+    pub fn schemes(&self) -> Result<Option<Vec<Scheme>>, String> {
+        self.schemes
+            .as_ref()
+            .map(|ids| ids.iter().map(|id| id.parse()).collect())
+            .transpose()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        self.schemes()?;
+        let non_empty = |field: &str, values: &Option<Vec<usize>>| match values {
+            Some(v) if v.is_empty() => Err(format!("{} is empty", field)),
+            Some(v) if v.contains(&0) => Err(format!("{} contains 0", field)),
+            _ => Ok(()),
+        };
+        non_empty("tx_counts", &self.tx_counts)?;
+        non_empty("payload_bytes", &self.payload_bytes)?;
+        if self.segments == Some(0) || self.keypairs == Some(0) {
+            return Err("segments and keypairs must be at least 1".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assertions::SpeedupAssertion;
+
+    fn suite(fields: &str) -> String {
+        format!("[[suite]]\nname = \"s\"\n{}\n", fields)
+    }
+
+    #[test]
+    fn bundled_scenarios_parse_and_validate() {
+        for (name, toml) in BUNDLED {
+            let scenario = Scenario::parse(toml).unwrap();
+            assert_eq!(scenario.select(&[]).unwrap().len(), scenario.suites.len());
+            assert!(scenario.suites.iter().any(|s| s.name == *name));
+        }
+    }
+
+    #[test]
+    fn parses_every_field() {
+        let scenario = Scenario::parse(&suite(
+            r#"
+            schemes = ["shake-128f"]
+            tx_counts = [10, 100]
+            sweep = "pow2:64"
+            thread_sweep = [1, 2, 4]
+            segments = 16
+            keypairs = 4
+            payload_bytes = [80, 256]
+            iterations = 5
+            warmup = 1
+            both_paths = true
+            [suite.assertions]
+            min_sequential_tps = 100.0
+            min_speedup = [{ segments = 16, speedup = 2.0 }]
+            "#,
+        ))
+        .unwrap();
+        let suite = &scenario.suites[0];
+        assert_eq!(suite.tx_counts, Some(vec![10, 100]));
+        assert_eq!(suite.schemes().unwrap().map(|s| s.len()), Some(1));
+        assert_eq!(suite.assertions.min_speedup, vec![SpeedupAssertion { segments: 16, speedup: 2.0 }]);
+        assert!(suite.description.is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_fields() {
+        assert!(Scenario::parse(&suite("tx_count = [10]")).is_err());
+        assert!(Scenario::parse(&suite("[suite.assertions]\nmin_tps = 1.0")).is_err());
+        assert!(Scenario::parse("name = \"top\"\n[[suite]]\nname = \"s\"").is_err());
+    }
+
+    #[test]
+    fn rejects_empty_and_zero_values() {
+        for fields in [
+            "tx_counts = []",
+            "tx_counts = [10, 0]",
+            "payload_bytes = []",
+            "payload_bytes = [0]",
+            "segments = 0",
+            "keypairs = 0",
+            "schemes = [\"rsa\"]",
+        ] {
+            assert!(Scenario::parse(&suite(fields)).is_err(), "{}", fields);
+        }
+    }
+
+    #[test]
+    fn rejects_unnamed_duplicate_and_missing_suites() {
+        assert!(Scenario::parse("").is_err());
+        assert!(Scenario::parse("[[suite]]\ntx_counts = [10]").is_err());
+        assert!(Scenario::parse("[[suite]]\nname = \"s\"\n[[suite]]\nname = \"s\"").is_err());
+    }
+
+    #[test]
+    fn selects_suites_by_name() {
+        let scenario = Scenario::parse("[[suite]]\nname = \"a\"\n[[suite]]\nname = \"b\"").unwrap();
+        let selected = scenario.select(&["b".to_string()]).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name, "b");
+        assert!(scenario.select(&["c".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+//! Ed25519 classical baseline
+//!
+//! This is what most chains use today. It is not quantum safe; it is here so
+//! the SPHINCS+ overhead is measured on the same machine instead of quoted.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+
+use super::SignatureScheme;
+
+pub struct Ed25519;
+
+impl SignatureScheme for Ed25519 {
+    const ID: &'static str = "ed25519";
+    const NAME: &'static str = "Ed25519";
+    const FAMILY: &'static str = "Ed25519";
+    const NIST_LEVEL: u8 = 0;
+    const PUBLIC_KEY_BYTES: usize = ed25519_dalek::PUBLIC_KEY_LENGTH;
+    const SECRET_KEY_BYTES: usize = ed25519_dalek::SECRET_KEY_LENGTH;
+    const SIGNATURE_BYTES: usize = ed25519_dalek::SIGNATURE_LENGTH;
+
+    fn keypair() -> (Vec<u8>, Vec<u8>) {
+        let sk = SigningKey::generate(&mut OsRng);
+        (sk.verifying_key().to_bytes().to_vec(), sk.to_bytes().to_vec())
+    }
+
+    fn sign(message: &[u8], secret_key: &[u8]) -> Vec<u8> {
+        let sk = SigningKey::from_bytes(secret_key.try_into().expect("Invalid secret key"));
+        sk.sign(message).to_bytes().to_vec()
+    }
+
+    fn verify(message: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+        let Ok(pk_bytes) = public_key.try_into() else {
+            return false;
+        };
+        let (Ok(pk), Ok(sig)) = (VerifyingKey::from_bytes(pk_bytes), Signature::from_slice(signature))
+        else {
+            return false;
+        };
+        pk.verify(message, &sig).is_ok()
+    }
+
+    /// Ed25519 has no native signed-message format, so mirror the PQClean
+    /// layout: signature followed by the message.
+    fn sign_attached(message: &[u8], secret_key: &[u8]) -> Vec<u8> {
+        let mut signed = Self::sign(message, secret_key);
+        signed.extend_from_slice(message);
+        signed
+    }
+
+    fn open(signed_message: &[u8], public_key: &[u8]) -> Option<Vec<u8>> {
+        if signed_message.len() < Self::SIGNATURE_BYTES {
+            return None;
+        }
+        let (sig, message) = signed_message.split_at(Self::SIGNATURE_BYTES);
+        Self::verify(message, sig, public_key).then(|| message.to_vec())
+    }
+}
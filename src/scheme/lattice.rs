@@ -0,0 +1,18 @@
+//! Lattice-based post-quantum signatures: ML-DSA (FIPS 204, Dilithium) and
+//! Falcon (FN-DSA). Both verify orders of magnitude faster than SPHINCS+ and
+//! have much smaller signatures, which is the trade-off the comparison
+//! tables are meant to show.
+
+use super::pqclean_scheme;
+
+#[cfg(feature = "mldsa")]
+pqclean_scheme!(MlDsa44, pqcrypto_mldsa::mldsa44, "ml-dsa-44", "ML-DSA-44", "ML-DSA", 2);
+#[cfg(feature = "mldsa")]
+pqclean_scheme!(MlDsa65, pqcrypto_mldsa::mldsa65, "ml-dsa-65", "ML-DSA-65", "ML-DSA", 3);
+#[cfg(feature = "mldsa")]
+pqclean_scheme!(MlDsa87, pqcrypto_mldsa::mldsa87, "ml-dsa-87", "ML-DSA-87", "ML-DSA", 5);
+
+#[cfg(feature = "falcon")]
+pqclean_scheme!(Falcon512, pqcrypto_falcon::falcon512, "falcon-512", "Falcon-512", "Falcon", 1);
+#[cfg(feature = "falcon")]
+pqclean_scheme!(Falcon1024, pqcrypto_falcon::falcon1024, "falcon-1024", "Falcon-1024", "Falcon", 5);
@@ -0,0 +1,277 @@
+//! Pluggable signature schemes
+//!
+//! The benchmark pipeline is generic over [`SignatureScheme`], so SPHINCS+,
+//! the lattice schemes (ML-DSA, Falcon) and a classical Ed25519 baseline are
+//! measured with exactly the same transaction model on the same machine.
+//! [`Scheme`] is the runtime (CLI) selector; [`Scheme::visit`] turns it back
+//! into a concrete type for the generic code.
+
+#[cfg(feature = "ed25519")]
+mod ed25519;
+#[cfg(any(feature = "mldsa", feature = "falcon"))]
+mod lattice;
+mod sphincs;
+
+use clap::builder::PossibleValue;
+use clap::ValueEnum;
+
+/// A signature algorithm that can be benchmarked
+pub trait SignatureScheme: Send + Sync + 'static {
+    /// Short name as accepted by `--scheme`
+    const ID: &'static str;
+    /// Human readable algorithm name
+    const NAME: &'static str;
+    /// Algorithm family, used to group rows in comparison tables
+    const FAMILY: &'static str;
+    /// NIST post-quantum security category (1, 3 or 5), 0 for classical schemes
+    const NIST_LEVEL: u8;
+    const PUBLIC_KEY_BYTES: usize;
+    const SECRET_KEY_BYTES: usize;
+    /// Maximum signature size in bytes (Falcon signatures are variable length)
+    const SIGNATURE_BYTES: usize;
+
+    /// Generate a keypair, returned as (public_key, secret_key) bytes
+    fn keypair() -> (Vec<u8>, Vec<u8>);
+
+    /// Sign a message, returning a detached signature
+    fn sign(message: &[u8], secret_key: &[u8]) -> Vec<u8>;
+
+    /// Verify a detached signature over `message`
+    fn verify(message: &[u8], signature: &[u8], public_key: &[u8]) -> bool;
+
+    /// Sign a message, returning the signed message bytes (signature and message)
+    fn sign_attached(message: &[u8], secret_key: &[u8]) -> Vec<u8>;
+
+    /// Open a signed message, returning the message if the signature is valid
+    fn open(signed_message: &[u8], public_key: &[u8]) -> Option<Vec<u8>>;
+
+    fn info() -> SchemeInfo {
+        SchemeInfo {
+            id: Self::ID,
+            name: Self::NAME,
+            family: Self::FAMILY,
+            nist_level: Self::NIST_LEVEL,
+            public_key_bytes: Self::PUBLIC_KEY_BYTES,
+            secret_key_bytes: Self::SECRET_KEY_BYTES,
+            signature_bytes: Self::SIGNATURE_BYTES,
+        }
+    }
+}
+
+/// Static description of a scheme, for reporting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchemeInfo {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub family: &'static str,
+    pub nist_level: u8,
+    pub public_key_bytes: usize,
+    pub secret_key_bytes: usize,
+    pub signature_bytes: usize,
+}
+
+impl SchemeInfo {
+    pub fn is_post_quantum(&self) -> bool {
+        self.nist_level > 0
+    }
+}
+
+/// Implements [`SignatureScheme`] for a PQClean-backed `pqcrypto_*` module.
+/// All of them share the same keypair/sign/open API.
+macro_rules! pqclean_scheme {
+    ($ty:ident, $module:path, $id:literal, $name:literal, $family:expr, $level:literal) => {
+        pub struct $ty;
+
+        impl $crate::scheme::SignatureScheme for $ty {
+            const ID: &'static str = $id;
+            const NAME: &'static str = $name;
+            const FAMILY: &'static str = $family;
+            const NIST_LEVEL: u8 = $level;
+            const PUBLIC_KEY_BYTES: usize = {
+                use $module as m;
+                m::public_key_bytes()
+            };
+            const SECRET_KEY_BYTES: usize = {
+                use $module as m;
+                m::secret_key_bytes()
+            };
+            const SIGNATURE_BYTES: usize = {
+                use $module as m;
+                m::signature_bytes()
+            };
+
+            fn keypair() -> (Vec<u8>, Vec<u8>) {
+                use pqcrypto_traits::sign::{PublicKey, SecretKey};
+                use $module as m;
+                let (pk, sk) = m::keypair();
+                (pk.as_bytes().to_vec(), sk.as_bytes().to_vec())
+            }
+
+            fn sign(message: &[u8], secret_key: &[u8]) -> Vec<u8> {
+                use pqcrypto_traits::sign::{DetachedSignature, SecretKey};
+                use $module as m;
+                let sk = m::SecretKey::from_bytes(secret_key).expect("Invalid secret key");
+                m::detached_sign(message, &sk).as_bytes().to_vec()
+            }
+
+            fn verify(message: &[u8], signature: &[u8], public_key: &[u8]) -> bool {
+                use pqcrypto_traits::sign::{DetachedSignature, PublicKey};
+                use $module as m;
+                let (Ok(pk), Ok(sig)) = (
+                    m::PublicKey::from_bytes(public_key),
+                    m::DetachedSignature::from_bytes(signature),
+                ) else {
+                    return false;
+                };
+                m::verify_detached_signature(&sig, message, &pk).is_ok()
+            }
+
+            fn sign_attached(message: &[u8], secret_key: &[u8]) -> Vec<u8> {
+                use pqcrypto_traits::sign::{SecretKey, SignedMessage};
+                use $module as m;
+                let sk = m::SecretKey::from_bytes(secret_key).expect("Invalid secret key");
+                m::sign(message, &sk).as_bytes().to_vec()
+            }
+
+            fn open(signed_message: &[u8], public_key: &[u8]) -> Option<Vec<u8>> {
+                use pqcrypto_traits::sign::{PublicKey, SignedMessage};
+                use $module as m;
+                let pk = m::PublicKey::from_bytes(public_key).ok()?;
+                let sm = m::SignedMessage::from_bytes(signed_message).ok()?;
+                m::open(&sm, &pk).ok()
+            }
+        }
+    };
+}
+use pqclean_scheme;
+
+/// Something that runs generic code for a scheme chosen at runtime
+pub trait SchemeVisitor {
+    type Output;
+
+    fn visit<S: SignatureScheme>(self) -> Self::Output;
+}
+
+macro_rules! schemes {
+    ($($(#[$cfg:meta])* $variant:ident => $ty:ty;)*) => {
+        /// Runtime selector for every scheme compiled into this binary
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum Scheme {
+            $($(#[$cfg])* $variant,)*
+        }
+
+        impl Scheme {
+            /// Every available scheme, SPHINCS+ first
+            pub const ALL: &'static [Scheme] = &[$($(#[$cfg])* Scheme::$variant,)*];
+
+            /// Call `visitor` with the concrete scheme type
+            pub fn visit<V: SchemeVisitor>(self, visitor: V) -> V::Output {
+                match self {
+                    $($(#[$cfg])* Scheme::$variant => visitor.visit::<$ty>(),)*
+                }
+            }
+        }
+    };
+}
+
+schemes! {
+    Sha2_128f => sphincs::Sha2_128f;
+    Sha2_128s => sphincs::Sha2_128s;
+    Sha2_192f => sphincs::Sha2_192f;
+    Sha2_192s => sphincs::Sha2_192s;
+    Sha2_256f => sphincs::Sha2_256f;
+    Sha2_256s => sphincs::Sha2_256s;
+    Shake128f => sphincs::Shake128f;
+    Shake128s => sphincs::Shake128s;
+    Shake192f => sphincs::Shake192f;
+    Shake192s => sphincs::Shake192s;
+    Shake256f => sphincs::Shake256f;
+    Shake256s => sphincs::Shake256s;
+    #[cfg(feature = "mldsa")]
+    MlDsa44 => lattice::MlDsa44;
+    #[cfg(feature = "mldsa")]
+    MlDsa65 => lattice::MlDsa65;
+    #[cfg(feature = "mldsa")]
+    MlDsa87 => lattice::MlDsa87;
+    #[cfg(feature = "falcon")]
+    Falcon512 => lattice::Falcon512;
+    #[cfg(feature = "falcon")]
+    Falcon1024 => lattice::Falcon1024;
+    #[cfg(feature = "ed25519")]
+    Ed25519 => ed25519::Ed25519;
+}
+
+struct InfoVisitor;
+
+impl SchemeVisitor for InfoVisitor {
+    type Output = SchemeInfo;
+
+    fn visit<S: SignatureScheme>(self) -> SchemeInfo {
+        S::info()
+    }
+}
+
+impl Scheme {
+    pub fn info(self) -> SchemeInfo {
+        self.visit(InfoVisitor)
+    }
+
+    /// The twelve FIPS 205 SPHINCS+ parameter sets
+    pub fn sphincs() -> Vec<Scheme> {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(|s| s.info().family == sphincs::FAMILY)
+            .collect()
+    }
+}
+
+impl ValueEnum for Scheme {
+    fn value_variants<'a>() -> &'a [Self] {
+        Self::ALL
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(PossibleValue::new(self.info().id))
+    }
+}
+
+impl std::str::FromStr for Scheme {
+    type Err = String;
+
+    /// Parse a scheme id such as `shake-128f` (case-insensitive)
+    fn from_str(id: &str) -> Result<Self, String> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|s| s.info().id.eq_ignore_ascii_case(id.trim()))
+            .ok_or_else(|| {
+                let known: Vec<&str> = Self::ALL.iter().map(|s| s.info().id).collect();
+                format!("unknown or disabled scheme '{}' (available: {})", id, known.join(", "))
+            })
+    }
+}
+
+impl std::fmt::Display for Scheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.info().name)
+    }
+}
+
+/// Which schemes `--matrix` runs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MatrixSet {
+    /// All twelve SPHINCS+ parameter sets
+    Sphincs,
+    /// Every scheme compiled into this binary, including the non-SPHINCS+ baselines
+    All,
+}
+
+impl MatrixSet {
+    pub fn schemes(self) -> Vec<Scheme> {
+        match self {
+            MatrixSet::Sphincs => Scheme::sphincs(),
+            MatrixSet::All => Scheme::ALL.to_vec(),
+        }
+    }
+}
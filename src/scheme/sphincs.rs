@@ -0,0 +1,23 @@
+//! SPHINCS+ (SLH-DSA) parameter sets
+//!
+//! FIPS 205 standardizes twelve SPHINCS+ variants: SHA2 and SHAKE hash
+//! families at 128/192/256-bit security, each in a fast (`f`, larger
+//! signatures) and small (`s`, slower signing) flavor. Every variant lives in
+//! its own `pqcrypto_sphincsplus` module.
+
+use super::pqclean_scheme;
+
+pub const FAMILY: &str = "SPHINCS+";
+
+pqclean_scheme!(Sha2_128f, pqcrypto_sphincsplus::sphincssha2128fsimple, "sha2-128f", "SPHINCS+-SHA2-128f-simple", FAMILY, 1);
+pqclean_scheme!(Sha2_128s, pqcrypto_sphincsplus::sphincssha2128ssimple, "sha2-128s", "SPHINCS+-SHA2-128s-simple", FAMILY, 1);
+pqclean_scheme!(Sha2_192f, pqcrypto_sphincsplus::sphincssha2192fsimple, "sha2-192f", "SPHINCS+-SHA2-192f-simple", FAMILY, 3);
+pqclean_scheme!(Sha2_192s, pqcrypto_sphincsplus::sphincssha2192ssimple, "sha2-192s", "SPHINCS+-SHA2-192s-simple", FAMILY, 3);
+pqclean_scheme!(Sha2_256f, pqcrypto_sphincsplus::sphincssha2256fsimple, "sha2-256f", "SPHINCS+-SHA2-256f-simple", FAMILY, 5);
+pqclean_scheme!(Sha2_256s, pqcrypto_sphincsplus::sphincssha2256ssimple, "sha2-256s", "SPHINCS+-SHA2-256s-simple", FAMILY, 5);
+pqclean_scheme!(Shake128f, pqcrypto_sphincsplus::sphincsshake128fsimple, "shake-128f", "SPHINCS+-SHAKE-128f-simple", FAMILY, 1);
+pqclean_scheme!(Shake128s, pqcrypto_sphincsplus::sphincsshake128ssimple, "shake-128s", "SPHINCS+-SHAKE-128s-simple", FAMILY, 1);
+pqclean_scheme!(Shake192f, pqcrypto_sphincsplus::sphincsshake192fsimple, "shake-192f", "SPHINCS+-SHAKE-192f-simple", FAMILY, 3);
+pqclean_scheme!(Shake192s, pqcrypto_sphincsplus::sphincsshake192ssimple, "shake-192s", "SPHINCS+-SHAKE-192s-simple", FAMILY, 3);
+pqclean_scheme!(Shake256f, pqcrypto_sphincsplus::sphincsshake256fsimple, "shake-256f", "SPHINCS+-SHAKE-256f-simple", FAMILY, 5);
+pqclean_scheme!(Shake256s, pqcrypto_sphincsplus::sphincsshake256ssimple, "shake-256s", "SPHINCS+-SHAKE-256s-simple", FAMILY, 5);
@@ -0,0 +1,705 @@
+//! Discrete-event network simulator
+//!
+//! Explores validator sets that do not exist yet ("100 validators, 2 s
+//! blocks") from locally measured verify rates. Events are processed in time
+//! order from a queue:
+//!
+//! - transactions arrive as a Poisson stream at a random validator and are
+//!   gossiped to the others after one gossip delay; a bounded mempool drops
+//!   arrivals once full
+//! - the slot's author (Aura) or view's leader (HotStuff), chosen
+//!   round-robin, fills a block with the transactions it has seen, limited by
+//!   block size and by how many it can verify within the block weight
+//! - every other validator receives the block after a gossip delay and
+//!   verifies all of it, one message at a time
+//!
+//! Under [`Consensus::Aura`] the author seals the block with one signature
+//! and the others verify it. An author that has not finished importing the
+//! previous block when its slot starts builds on the block before it: the
+//! unimported block is orphaned and its transactions return to the mempool.
+//! Aura has no finality gadget here; a block counts as final once a child is
+//! built on it, after which it can no longer be orphaned.
+//!
+//! Under [`Consensus::Hotstuff`] each view runs basic (unpipelined) HotStuff:
+//! the leader signs a proposal carrying the previous view's quorum
+//! certificate, and every validator signs a vote in each of the prepare,
+//! pre-commit and commit phases. Hash-based signatures do not aggregate, so
+//! a quorum certificate is 2f + 1 separate signatures that every replica
+//! verifies; the leader verifies votes in arrival order until it has a
+//! quorum. The block is final when the commit certificate forms. Views start
+//! no faster than one per block time; leader failures and view changes are
+//! not modeled.
+//!
+//! Delays are drawn uniformly from 0.5-1.5× the configured gossip latency.
+//! Execution and storage are not modeled.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt;
+
+use crate::latency::LatencyHistogram;
+use crate::report::LatencyRow;
+
+/// Substrate's block length limit: 5 MiB, 75% of it for normal transactions
+pub const DEFAULT_BLOCK_BYTES: usize = 3_932_160;
+/// Substrate's default transaction pool ready limit
+pub const DEFAULT_MEMPOOL_LIMIT: usize = 8192;
+
+/// Block production and finality protocol to simulate
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Consensus {
+    /// Round-robin slots, one seal signature per block
+    #[default]
+    Aura,
+    /// Three voting phases per block, a quorum certificate per phase
+    Hotstuff,
+}
+
+impl fmt::Display for Consensus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Consensus::Aura => "Aura",
+            Consensus::Hotstuff => "HotStuff",
+        })
+    }
+}
+
+/// What to simulate
+#[derive(Clone, Debug)]
+pub struct SimConfig {
+    pub consensus: Consensus,
+    /// Verify TPS of each validator, in authoring order
+    pub verify_tps: Vec<f64>,
+    /// Seconds to sign one seal, proposal or vote
+    pub sign_secs: f64,
+    pub signature_bytes: usize,
+    pub block_time_secs: f64,
+    /// Seconds of each block the author may spend verifying transactions
+    pub block_weight_secs: f64,
+    pub max_block_bytes: usize,
+    /// Encoded size of one transaction (signature, public key, payload)
+    pub tx_bytes: usize,
+    /// Mean one-hop gossip latency for transactions, blocks and votes
+    pub gossip_ms: f64,
+    pub mempool_limit: usize,
+    /// Poisson arrival rate of new transactions
+    pub offered_tps: f64,
+    pub duration_secs: f64,
+    pub seed: u64,
+}
+
+/// Simulation outcome
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimulationMetrics {
+    #[serde(default)]
+    pub consensus: Consensus,
+    pub validators: usize,
+    /// Votes in one quorum certificate, 0 for Aura
+    #[serde(default)]
+    pub quorum: usize,
+    #[serde(default)]
+    pub sign_ms: f64,
+    #[serde(default)]
+    pub signature_bytes: usize,
+    pub block_time_secs: f64,
+    pub block_weight_secs: f64,
+    pub max_block_bytes: usize,
+    pub tx_bytes: usize,
+    pub gossip_ms: f64,
+    pub mempool_limit: usize,
+    pub offered_tps: f64,
+    pub duration_secs: f64,
+    pub seed: u64,
+    pub submitted: u64,
+    /// Transactions in canonical blocks
+    pub included: u64,
+    /// Transactions in final blocks
+    #[serde(default)]
+    pub finalized: u64,
+    /// Arrivals rejected by a full mempool
+    pub dropped: u64,
+    /// Still in the mempool when the simulation ended
+    pub pending: u64,
+    /// Canonical blocks
+    pub blocks: u64,
+    pub orphaned_blocks: u64,
+    /// Blocks that hit the size or weight limit
+    pub full_blocks: u64,
+    pub simulated_tps: f64,
+    /// Arrival to block production, for included transactions
+    pub inclusion_latency: LatencyRow,
+    /// Arrival to finality, for finalized transactions
+    #[serde(default)]
+    pub finality_latency: LatencyRow,
+    /// Seals, proposals and votes signed, across all validators
+    #[serde(default)]
+    pub consensus_signatures: u64,
+    /// Seal, proposal and vote signatures verified, across all validators
+    #[serde(default)]
+    pub consensus_verifications: u64,
+    /// Transaction signatures verified, across all validators
+    #[serde(default)]
+    pub tx_verifications: u64,
+    /// Per validator, in authoring order
+    #[serde(default)]
+    pub load: Vec<ValidatorLoad>,
+    /// Mempool size after each slot
+    pub backlog: Vec<BacklogSample>,
+}
+
+/// Signing and verification work done by one validator
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ValidatorLoad {
+    pub tx_verifications: u64,
+    pub consensus_verifications: u64,
+    pub signatures: u64,
+    /// Seconds spent verifying and signing
+    pub busy_secs: f64,
+    /// `busy_secs` as a share of the simulated time
+    pub utilization: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BacklogSample {
+    pub time_secs: f64,
+    pub pending: usize,
+    /// Transactions included in canonical blocks so far
+    pub included: u64,
+}
+
+impl SimulationMetrics {
+    /// Consensus signatures per produced block, orphans included
+    pub fn signatures_per_block(&self) -> f64 {
+        per_block(self.consensus_signatures, self.blocks + self.orphaned_blocks)
+    }
+
+    /// Consensus verifications per produced block, across all validators
+    pub fn verifications_per_block(&self) -> f64 {
+        per_block(self.consensus_verifications, self.blocks + self.orphaned_blocks)
+    }
+
+    /// The validator with the highest utilization, and its index
+    pub fn busiest(&self) -> Option<(usize, &ValidatorLoad)> {
+        self.load.iter().enumerate().max_by(|a, b| a.1.utilization.total_cmp(&b.1.utilization))
+    }
+
+    /// Minimum, mean and maximum utilization across validators
+    pub fn utilization(&self) -> (f64, f64, f64) {
+        if self.load.is_empty() {
+            return (0.0, 0.0, 0.0);
+        }
+        let busy = self.load.iter().map(|l| l.utilization);
+        let min = busy.clone().fold(f64::INFINITY, f64::min);
+        let max = busy.clone().fold(0.0, f64::max);
+        (min, busy.sum::<f64>() / self.load.len() as f64, max)
+    }
+}
+
+/// Labeled values comparing runs of the same load, one value per run
+pub fn compare(runs: &[&SimulationMetrics]) -> Vec<(&'static str, Vec<String>)> {
+    let row = |label, value: &dyn Fn(&SimulationMetrics) -> String| (label, runs.iter().map(|r| value(r)).collect());
+    vec![
+        row("Simulated TPS", &|r| format!("{:.1}", r.simulated_tps)),
+        row("Inclusion p50", &|r| format!("{:.0} ms", r.inclusion_latency.p50_ms)),
+        row("Finality p50", &|r| format!("{:.0} ms", r.finality_latency.p50_ms)),
+        row("Finality p99", &|r| format!("{:.0} ms", r.finality_latency.p99_ms)),
+        row("Signatures per block", &|r| format!("{:.0}", r.signatures_per_block())),
+        row("Verifications per block", &|r| format!("{:.0}", r.verifications_per_block())),
+        row("Busiest validator", &|r| format!("{:.1}%", r.utilization().2 * 100.0)),
+        row("Orphaned blocks", &|r| r.orphaned_blocks.to_string()),
+    ]
+}
+
+fn per_block(count: u64, blocks: u64) -> f64 {
+    if blocks == 0 {
+        0.0
+    } else {
+        count as f64 / blocks as f64
+    }
+}
+
+enum Event {
+    Arrival,
+    Slot(u64),
+}
+
+struct Scheduled {
+    time: f64,
+    /// Breaks ties in insertion order
+    seq: u64,
+    event: Event,
+}
+
+impl PartialEq for Scheduled {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Scheduled {}
+
+impl PartialOrd for Scheduled {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scheduled {
+    /// Reversed, so the `BinaryHeap` pops the earliest event first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.time.total_cmp(&self.time).then(other.seq.cmp(&self.seq))
+    }
+}
+
+struct PendingTx {
+    arrival: f64,
+    origin: usize,
+    /// When every other validator has the transaction
+    gossiped_at: f64,
+}
+
+struct Block {
+    produced_at: f64,
+    finalized_at: Option<f64>,
+    /// Arrival times of the included transactions
+    arrivals: Vec<f64>,
+}
+
+/// Network state shared by both consensus models
+struct Network<'a> {
+    config: &'a SimConfig,
+    n: usize,
+    rng: StdRng,
+    mempool: VecDeque<PendingTx>,
+    chain: Vec<Block>,
+    /// When each validator finishes its queued verification and signing
+    busy_until: Vec<f64>,
+    /// When each validator finishes importing the current head (Aura)
+    head_imported: Vec<f64>,
+    load: Vec<ValidatorLoad>,
+    submitted: u64,
+    dropped: u64,
+    included: u64,
+    orphaned_blocks: u64,
+    full_blocks: u64,
+    backlog: Vec<BacklogSample>,
+}
+
+/// Run the simulation described by `config`
+pub fn simulate(config: &SimConfig) -> SimulationMetrics {
+    let mut network = Network::new(config);
+
+    let mut queue = BinaryHeap::new();
+    let mut seq = 0;
+    let mut schedule = |queue: &mut BinaryHeap<Scheduled>, time: f64, event: Event| {
+        seq += 1;
+        queue.push(Scheduled { time, seq, event });
+    };
+    if config.offered_tps > 0.0 {
+        let first = exponential(&mut network.rng, config.offered_tps);
+        schedule(&mut queue, first, Event::Arrival);
+    }
+    schedule(&mut queue, config.block_time_secs, Event::Slot(1));
+
+    while let Some(Scheduled { time, event, .. }) = queue.pop() {
+        if time > config.duration_secs {
+            break;
+        }
+        match event {
+            Event::Arrival => {
+                network.arrive(time);
+                let next = time + exponential(&mut network.rng, config.offered_tps);
+                schedule(&mut queue, next, Event::Arrival);
+            }
+            Event::Slot(slot) => {
+                let next = match config.consensus {
+                    Consensus::Aura => network.aura_slot(slot, time),
+                    Consensus::Hotstuff => network.hotstuff_view(slot, time),
+                };
+                network.backlog.push(BacklogSample {
+                    time_secs: time,
+                    pending: network.mempool.len(),
+                    included: network.included,
+                });
+                schedule(&mut queue, next, Event::Slot(slot + 1));
+            }
+        }
+    }
+    network.finish()
+}
+
+impl<'a> Network<'a> {
+    fn new(config: &'a SimConfig) -> Self {
+        let n = config.verify_tps.len().max(1);
+        Self {
+            config,
+            n,
+            rng: StdRng::seed_from_u64(config.seed),
+            mempool: VecDeque::new(),
+            chain: Vec::new(),
+            busy_until: vec![0.0; n],
+            head_imported: vec![0.0; n],
+            load: vec![ValidatorLoad::default(); n],
+            submitted: 0,
+            dropped: 0,
+            included: 0,
+            orphaned_blocks: 0,
+            full_blocks: 0,
+            backlog: Vec::new(),
+        }
+    }
+
+    /// Signatures in one quorum certificate: 2f + 1 of n = 3f + 1
+    fn quorum(&self) -> usize {
+        self.n - (self.n - 1) / 3
+    }
+
+    fn gossip_delay(&mut self) -> f64 {
+        self.config.gossip_ms / 1000.0 * self.rng.gen_range(0.5..1.5)
+    }
+
+    fn rate(&self, validator: usize) -> f64 {
+        self.config.verify_tps.get(validator).copied().unwrap_or(0.0).max(f64::MIN_POSITIVE)
+    }
+
+    fn arrive(&mut self, time: f64) {
+        self.submitted += 1;
+        if self.mempool.len() >= self.config.mempool_limit {
+            self.dropped += 1;
+            return;
+        }
+        let origin = self.rng.gen_range(0..self.n);
+        let gossiped_at = time + self.gossip_delay();
+        self.mempool.push_back(PendingTx { arrival: time, origin, gossiped_at });
+    }
+
+    /// `validator` verifies `txs` transaction and `votes` consensus signatures from `start`; returns when it is done
+    fn verify(&mut self, validator: usize, start: f64, txs: usize, votes: usize) -> f64 {
+        let secs = (txs + votes) as f64 / self.rate(validator);
+        let load = &mut self.load[validator];
+        load.tx_verifications += txs as u64;
+        load.consensus_verifications += votes as u64;
+        load.busy_secs += secs;
+        start + secs
+    }
+
+    /// `validator` signs one message from `start`; returns when it is done
+    fn sign(&mut self, validator: usize, start: f64) -> f64 {
+        let load = &mut self.load[validator];
+        load.signatures += 1;
+        load.busy_secs += self.config.sign_secs;
+        start + self.config.sign_secs
+    }
+
+    /// Take the transactions `author` can see at `time`, up to the block limits
+    fn take_block(&mut self, author: usize, time: f64) -> Vec<f64> {
+        let by_size = self.config.max_block_bytes / self.config.tx_bytes.max(1);
+        let limit = by_size.min((self.rate(author) * self.config.block_weight_secs) as usize);
+        let mut arrivals = Vec::new();
+        self.mempool.retain(|tx| {
+            let visible = tx.origin == author || tx.gossiped_at <= time;
+            if visible && arrivals.len() < limit {
+                arrivals.push(tx.arrival);
+                false
+            } else {
+                true
+            }
+        });
+        if limit > 0 && arrivals.len() == limit {
+            self.full_blocks += 1;
+        }
+        arrivals
+    }
+
+    /// Author one Aura block; returns when the next slot starts
+    fn aura_slot(&mut self, slot: u64, time: f64) -> f64 {
+        let author = (slot % self.n as u64) as usize;
+
+        // Still importing the head: build on its parent and orphan it
+        if self.head_imported[author] > time {
+            if let Some(orphan) = self.chain.pop() {
+                self.orphaned_blocks += 1;
+                self.included -= orphan.arrivals.len() as u64;
+                for arrival in orphan.arrivals {
+                    // Returned transactions are known to every validator by now
+                    self.mempool.push_back(PendingTx { arrival, origin: author, gossiped_at: arrival });
+                }
+                self.mempool.make_contiguous().sort_by(|a, b| a.arrival.total_cmp(&b.arrival));
+                if let Some(parent) = self.chain.last_mut() {
+                    parent.finalized_at = None;
+                }
+            }
+            self.head_imported[author] = 0.0;
+        }
+
+        let arrivals = self.take_block(author, time);
+        let txs = arrivals.len();
+        let verified = self.verify(author, time.max(self.busy_until[author]), txs, 0);
+        let produced_at = self.sign(author, verified);
+        for v in 0..self.n {
+            let done = if v == author {
+                produced_at
+            } else {
+                let start = (produced_at + self.gossip_delay()).max(self.busy_until[v]);
+                self.verify(v, start, txs, 1)
+            };
+            self.busy_until[v] = done;
+            self.head_imported[v] = done;
+        }
+
+        if let Some(parent) = self.chain.last_mut() {
+            parent.finalized_at = Some(produced_at);
+        }
+        self.included += txs as u64;
+        self.chain.push(Block { produced_at, finalized_at: None, arrivals });
+        time + self.config.block_time_secs
+    }
+
+    /// Run one HotStuff view to its commit certificate; returns when the next view starts
+    fn hotstuff_view(&mut self, view: u64, time: f64) -> f64 {
+        let leader = (view % self.n as u64) as usize;
+        let quorum = self.quorum();
+        let replicas: Vec<usize> = (0..self.n).filter(|&v| v != leader).collect();
+
+        let arrivals = self.take_block(leader, time);
+        let txs = arrivals.len();
+        let start = time.max(self.busy_until[leader]);
+        let produced_at = self.verify(leader, start, txs, 0);
+        let mut sent = self.sign(leader, produced_at);
+
+        // Prepare, pre-commit and commit: replicas verify what the leader sent and vote
+        for phase in 0..3 {
+            let (phase_txs, signatures) = match phase {
+                // The block, the proposal signature and the previous view's certificate
+                0 => (txs, 1 + if view > 1 { quorum } else { 0 }),
+                // The certificate from the phase before
+                _ => (0, quorum),
+            };
+            let mut votes = Vec::with_capacity(replicas.len());
+            for &v in &replicas {
+                let received = sent + self.gossip_delay();
+                let verified = self.verify(v, received.max(self.busy_until[v]), phase_txs, signatures);
+                let voted = self.sign(v, verified);
+                self.busy_until[v] = voted;
+                votes.push(voted + self.gossip_delay());
+            }
+            votes.sort_by(f64::total_cmp);
+
+            // The leader's own vote, then the others in arrival order until there is a quorum
+            let mut certified = self.sign(leader, sent);
+            for &arrived in votes.iter().take(quorum - 1) {
+                certified = self.verify(leader, certified.max(arrived), 0, 1);
+            }
+            sent = certified;
+        }
+        let finalized_at = sent;
+        self.busy_until[leader] = finalized_at;
+
+        // Decide: replicas verify the commit certificate
+        for &v in &replicas {
+            let received = finalized_at + self.gossip_delay();
+            self.busy_until[v] = self.verify(v, received.max(self.busy_until[v]), 0, quorum);
+        }
+
+        self.included += txs as u64;
+        self.chain.push(Block { produced_at, finalized_at: Some(finalized_at), arrivals });
+        (finalized_at + self.gossip_delay()).max(time + self.config.block_time_secs)
+    }
+
+    fn finish(mut self) -> SimulationMetrics {
+        let config = self.config;
+        let mut inclusion = LatencyHistogram::new();
+        let mut finality = LatencyHistogram::new();
+        let mut finalized = 0;
+        for block in &self.chain {
+            for &arrival in &block.arrivals {
+                inclusion.record(((block.produced_at - arrival) * 1e9) as u64);
+                if let Some(at) = block.finalized_at {
+                    finality.record(((at - arrival) * 1e9) as u64);
+                    finalized += 1;
+                }
+            }
+        }
+        for load in &mut self.load {
+            load.utilization = load.busy_secs / config.duration_secs;
+        }
+
+        SimulationMetrics {
+            consensus: config.consensus,
+            validators: self.n,
+            quorum: match config.consensus {
+                Consensus::Aura => 0,
+                Consensus::Hotstuff => self.quorum(),
+            },
+            sign_ms: config.sign_secs * 1000.0,
+            signature_bytes: config.signature_bytes,
+            block_time_secs: config.block_time_secs,
+            block_weight_secs: config.block_weight_secs,
+            max_block_bytes: config.max_block_bytes,
+            tx_bytes: config.tx_bytes,
+            gossip_ms: config.gossip_ms,
+            mempool_limit: config.mempool_limit,
+            offered_tps: config.offered_tps,
+            duration_secs: config.duration_secs,
+            seed: config.seed,
+            submitted: self.submitted,
+            included: self.included,
+            finalized,
+            dropped: self.dropped,
+            pending: self.mempool.len() as u64,
+            blocks: self.chain.len() as u64,
+            orphaned_blocks: self.orphaned_blocks,
+            full_blocks: self.full_blocks,
+            simulated_tps: self.included as f64 / config.duration_secs,
+            inclusion_latency: (&inclusion).into(),
+            finality_latency: (&finality).into(),
+            consensus_signatures: self.load.iter().map(|l| l.signatures).sum(),
+            consensus_verifications: self.load.iter().map(|l| l.consensus_verifications).sum(),
+            tx_verifications: self.load.iter().map(|l| l.tx_verifications).sum(),
+            load: self.load,
+            backlog: self.backlog,
+        }
+    }
+}
+
+/// Exponentially distributed gap between Poisson arrivals at `rate` per second
+fn exponential(rng: &mut StdRng, rate: f64) -> f64 {
+    -(1.0 - rng.gen::<f64>()).ln() / rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(consensus: Consensus, validators: usize) -> SimConfig {
+        SimConfig {
+            consensus,
+            verify_tps: vec![1000.0; validators],
+            sign_secs: 0.001,
+            signature_bytes: 64,
+            block_time_secs: 6.0,
+            block_weight_secs: 2.0,
+            max_block_bytes: DEFAULT_BLOCK_BYTES,
+            tx_bytes: 200,
+            gossip_ms: 150.0,
+            mempool_limit: DEFAULT_MEMPOOL_LIMIT,
+            offered_tps: 10.0,
+            duration_secs: 60.0,
+            seed: 7,
+        }
+    }
+
+    #[test]
+    fn quorum_is_n_minus_f() {
+        for n in 1..=100 {
+            let config = config(Consensus::Hotstuff, n);
+            let quorum = Network::new(&config).quorum();
+            let f = (n - 1) / 3;
+            assert_eq!(quorum, n - f, "n = {}", n);
+            // More than two thirds, and any two quorums share an honest validator
+            assert!(3 * quorum > 2 * n, "n = {}", n);
+            assert!(2 * quorum - n > f, "n = {}", n);
+            if n % 3 == 1 {
+                assert_eq!(quorum, 2 * f + 1, "n = {}", n);
+            }
+        }
+    }
+
+    #[test]
+    fn only_hotstuff_reports_a_quorum() {
+        assert_eq!(simulate(&config(Consensus::Hotstuff, 4)).quorum, 3);
+        assert_eq!(simulate(&config(Consensus::Hotstuff, 10)).quorum, 7);
+        assert_eq!(simulate(&config(Consensus::Aura, 4)).quorum, 0);
+    }
+
+    #[test]
+    fn aura_keeps_up_below_capacity() {
+        // 1000 TPS for 2 s of every 6 s block: capacity is 333 TPS
+        let sim = simulate(&SimConfig { offered_tps: 50.0, ..config(Consensus::Aura, 4) });
+        assert!((sim.simulated_tps - 50.0).abs() < 50.0 * 0.15, "{}", sim.simulated_tps);
+        assert_eq!((sim.dropped, sim.full_blocks, sim.orphaned_blocks), (0, 0, 0));
+        // Whatever arrived during the last slot, nothing older
+        assert!(sim.pending < 50 * 6 * 2, "{}", sim.pending);
+        assert!(sim.backlog.iter().all(|b| (b.pending as f64) < 50.0 * 6.0 * 0.5), "{:?}", sim.backlog);
+    }
+
+    #[test]
+    fn aura_backlog_grows_above_capacity() {
+        let sim = simulate(&SimConfig { offered_tps: 600.0, mempool_limit: 100_000, ..config(Consensus::Aura, 4) });
+        // Every block is cut off at the 2000 transactions its author can verify
+        assert_eq!(sim.full_blocks, sim.blocks);
+        assert_eq!(sim.included, sim.blocks * 2000);
+        let pending: Vec<usize> = sim.backlog.iter().map(|b| b.pending).collect();
+        assert!(pending.windows(2).all(|w| w[1] > w[0]), "{:?}", pending);
+        assert!(sim.simulated_tps < 2000.0 / 6.0 + 1.0);
+    }
+
+    #[test]
+    fn aura_full_mempool_drops_arrivals() {
+        let sim = simulate(&SimConfig { offered_tps: 600.0, mempool_limit: 1000, ..config(Consensus::Aura, 4) });
+        assert!(sim.dropped > 0);
+        assert!(sim.backlog.iter().all(|b| b.pending <= 1000));
+    }
+
+    #[test]
+    fn aura_orphans_blocks_slower_to_gossip_than_the_slot() {
+        let fast = config(Consensus::Aura, 4);
+        assert_eq!(simulate(&fast).orphaned_blocks, 0);
+        let sim = simulate(&SimConfig { block_time_secs: 0.2, block_weight_secs: 0.1, gossip_ms: 500.0, ..fast });
+        assert!(sim.orphaned_blocks > 0);
+        assert!(sim.blocks + sim.orphaned_blocks <= 60 * 5);
+        // Orphaned transactions go back to the mempool, not missing
+        assert_eq!(sim.included + sim.pending + sim.dropped, sim.submitted);
+    }
+
+    /// Consensus signatures each validator verifies per block, on average
+    fn verifications_per_validator(sim: &SimulationMetrics) -> f64 {
+        sim.verifications_per_block() / sim.validators as f64
+    }
+
+    #[test]
+    fn hotstuff_verification_load_grows_with_the_quorum() {
+        for n in [4, 10, 31] {
+            let sim = simulate(&config(Consensus::Hotstuff, n));
+            let quorum = sim.quorum as f64;
+            // Replicas verify three certificates and the decide certificate, plus the proposal
+            let per_validator = verifications_per_validator(&sim);
+            assert!(per_validator > 3.0 * quorum && per_validator < 4.0 * quorum + 2.0, "n = {}: {}", n, per_validator);
+        }
+        let small = verifications_per_validator(&simulate(&config(Consensus::Hotstuff, 4)));
+        let large = verifications_per_validator(&simulate(&config(Consensus::Hotstuff, 31)));
+        assert!(large > 5.0 * small, "{} vs {}", large, small);
+    }
+
+    #[test]
+    fn aura_verification_load_stays_flat() {
+        for n in [4, 10, 31] {
+            // Every validator but the author verifies one seal per block
+            let per_validator = verifications_per_validator(&simulate(&config(Consensus::Aura, n)));
+            let expected = (n - 1) as f64 / n as f64;
+            assert!((per_validator - expected).abs() < 1e-9, "n = {}: {}", n, per_validator);
+        }
+    }
+
+    #[test]
+    fn hotstuff_finality_takes_three_round_trips() {
+        let config = SimConfig { offered_tps: 0.0, ..config(Consensus::Hotstuff, 31) };
+        let gossip = config.gossip_ms / 1000.0;
+        let mut network = Network::new(&config);
+        let mut time = 0.0;
+        for view in 1..=20 {
+            time = network.hotstuff_view(view, time);
+        }
+        let commits: Vec<f64> =
+            network.chain.iter().map(|b| b.finalized_at.unwrap() - b.produced_at).collect();
+        // A hop takes at least half the mean gossip delay, so a round trip at least one mean delay
+        assert!(commits.iter().all(|&c| c >= 3.0 * gossip), "{:?}", commits);
+        // On average a round trip is two mean delays
+        let mean = commits.iter().sum::<f64>() / commits.len() as f64;
+        assert!(mean >= 3.0 * 2.0 * gossip, "{}", mean);
+    }
+}
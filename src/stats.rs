@@ -0,0 +1,160 @@
+//! Summary statistics for repeated trials
+//!
+//! Every reported throughput is the result of several timed runs after
+//! warmup. We report mean/median/stddev/min/max, a 95% confidence interval
+//! for the mean (Student's t, since trial counts are small) and flag outlier
+//! runs with Tukey's fences.
+
+/// Statistics over a set of samples (e.g. TPS per iteration)
+#[derive(Clone, Debug)]
+pub struct Stats {
+    pub samples: Vec<f64>,
+    pub mean: f64,
+    pub median: f64,
+    /// Sample standard deviation (n - 1)
+    pub stddev: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Half-width of the 95% confidence interval for the mean
+    pub ci95: f64,
+    /// Indices into `samples` outside the 1.5×IQR fences
+    pub outliers: Vec<usize>,
+}
+
+impl Stats {
+    pub fn from_samples(samples: Vec<f64>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        let n = samples.len();
+        let mut sorted = samples.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let stddev = if n > 1 {
+            (samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+        let ci95 = if n > 1 {
+            t_critical_95(n - 1) * stddev / (n as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        // Tukey's fences need enough points for quartiles to mean anything
+        let outliers = if n >= 4 {
+            let (q1, q3) = (quantile(&sorted, 0.25), quantile(&sorted, 0.75));
+            let iqr = q3 - q1;
+            let (lo, hi) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+            (0..n).filter(|&i| samples[i] < lo || samples[i] > hi).collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            mean,
+            median: quantile(&sorted, 0.5),
+            stddev,
+            min: sorted[0],
+            max: sorted[n - 1],
+            ci95,
+            outliers,
+            samples,
+        }
+    }
+
+    /// The flagged outlier samples themselves
+    pub fn outlier_values(&self) -> Vec<f64> {
+        self.outliers.iter().map(|&i| self.samples[i]).collect()
+    }
+}
+
+/// Welch's t-test at 95%: do `a` and `b` have different means?
+///
+/// `None` when either side has fewer than two samples, since there is no
+/// variance to test against.
+pub fn differs_significantly(a: &Stats, b: &Stats) -> Option<bool> {
+    let (na, nb) = (a.samples.len() as f64, b.samples.len() as f64);
+    if na < 2.0 || nb < 2.0 {
+        return None;
+    }
+    let (va, vb) = (a.stddev.powi(2) / na, b.stddev.powi(2) / nb);
+    if va + vb == 0.0 {
+        return Some(a.mean != b.mean);
+    }
+    let t = (a.mean - b.mean).abs() / (va + vb).sqrt();
+    // Welch–Satterthwaite degrees of freedom
+    let df = (va + vb).powi(2) / (va.powi(2) / (na - 1.0) + vb.powi(2) / (nb - 1.0));
+    Some(t > t_critical_95(df.floor().max(1.0) as usize))
+}
+
+/// Linear-interpolated quantile of sorted data
+pub fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let (lo, hi) = (pos.floor() as usize, pos.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+/// Two-sided 95% critical value of Student's t distribution
+pub fn t_critical_95(df: usize) -> f64 {
+    const TABLE: [f64; 30] = [
+        12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179,
+        2.160, 2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064,
+        2.060, 2.056, 2.052, 2.048, 2.045, 2.042,
+    ];
+    match df {
+        0 => f64::INFINITY,
+        1..=30 => TABLE[df - 1],
+        31..=60 => 2.000,
+        61..=120 => 1.980,
+        _ => 1.960,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn ci95_uses_student_t() {
+        // mean 5, sample stddev sqrt(2.5), t(4) = 2.776
+        let stats = Stats::from_samples(vec![3.0, 4.0, 5.0, 6.0, 7.0]);
+        assert!(close(stats.mean, 5.0));
+        assert!(close(stats.median, 5.0));
+        assert!(close(stats.stddev, 2.5f64.sqrt()));
+        assert!(close(stats.ci95, 2.776 * 2.5f64.sqrt() / 5f64.sqrt()));
+        assert_eq!(Stats::from_samples(vec![42.0]).ci95, 0.0);
+    }
+
+    #[test]
+    fn t_critical_falls_back_to_normal_for_large_df() {
+        assert_eq!(t_critical_95(1), 12.706);
+        assert_eq!(t_critical_95(30), 2.042);
+        assert_eq!(t_critical_95(1000), 1.960);
+        assert!(t_critical_95(0).is_infinite());
+    }
+
+    #[test]
+    fn tukey_fences_flag_outliers() {
+        // Q1 = 10.25, Q3 = 10.75, so the fences are 9.5 and 11.5
+        let stats = Stats::from_samples(vec![10.0, 10.5, 11.0, 10.5, 10.0, 11.0, 10.5, 30.0]);
+        assert_eq!(stats.outliers, vec![7]);
+        assert_eq!(stats.outlier_values(), vec![30.0]);
+        assert!(Stats::from_samples(vec![1.0, 2.0, 3.0, 4.0, 5.0]).outliers.is_empty());
+        // Too few points for quartiles
+        assert!(Stats::from_samples(vec![1.0, 1.0, 100.0]).outliers.is_empty());
+    }
+
+    #[test]
+    fn welch_separates_distinct_means() {
+        let a = Stats::from_samples(vec![100.0, 101.0, 99.0, 100.5, 99.5]);
+        let b = Stats::from_samples(vec![90.0, 91.0, 89.0, 90.5, 89.5]);
+        let c = Stats::from_samples(vec![100.2, 100.8, 99.1, 100.0, 99.9]);
+        assert_eq!(differs_significantly(&a, &b), Some(true));
+        assert_eq!(differs_significantly(&a, &c), Some(false));
+        assert_eq!(differs_significantly(&a, &Stats::from_samples(vec![100.0])), None);
+    }
+}
@@ -0,0 +1,261 @@
+//! Verification strategies
+//!
+//! Each strategy verifies the same transaction set in a different way, so
+//! segmentation can be compared against plain rayon work-stealing and a
+//! hand-rolled thread pool instead of only against the sequential baseline.
+
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::latency::LatencyHistogram;
+use crate::stats::Stats;
+use crate::torus::{MeshStats, TorusMesh};
+
+/// Something a strategy can verify
+pub trait Verifiable: Sync {
+    fn verify(&self) -> bool;
+
+    /// Home segment for segmenting strategies (taken modulo the segment count)
+    fn segment_id(&self) -> usize;
+}
+
+/// Result of one strategy run over a transaction set
+pub struct StrategyOutcome {
+    pub verified: usize,
+    pub duration: Duration,
+    /// Latency of every individual `verify` call
+    pub latency: LatencyHistogram,
+    /// Per-segment statistics, for the torus mesh strategy
+    pub mesh: Option<MeshStats>,
+}
+
+impl StrategyOutcome {
+    pub fn tps(&self) -> f64 {
+        self.verified as f64 / self.duration.as_secs_f64()
+    }
+}
+
+/// Repeated runs of one strategy, after warmup
+pub struct Trials {
+    /// Throughput of each measured run, in TPS
+    pub tps: Stats,
+    /// Verify latencies across all measured runs
+    pub latency: LatencyHistogram,
+    /// The last measured run (verified count, per-segment stats)
+    pub last: StrategyOutcome,
+}
+
+/// Run `strategy` `warmup` times untimed, then `iterations` times measured
+pub fn measure<T: Verifiable>(
+    strategy: &dyn VerificationStrategy<T>,
+    items: &[T],
+    warmup: usize,
+    iterations: usize,
+) -> Trials {
+    for _ in 0..warmup {
+        strategy.run(items);
+    }
+
+    let mut samples = Vec::with_capacity(iterations);
+    let mut latency = LatencyHistogram::new();
+    let mut last = None;
+    for _ in 0..iterations.max(1) {
+        let outcome = strategy.run(items);
+        samples.push(outcome.tps());
+        latency.merge(&outcome.latency);
+        last = Some(outcome);
+    }
+
+    Trials {
+        tps: Stats::from_samples(samples),
+        latency,
+        last: last.expect("at least one iteration"),
+    }
+}
+
+/// A way of spreading verification work across cores
+pub trait VerificationStrategy<T: Verifiable>: Send + Sync {
+    /// Label used in result tables
+    fn name(&self) -> String;
+
+    fn run(&self, items: &[T]) -> StrategyOutcome;
+}
+
+/// Verify one item, returning (valid, latency in nanoseconds)
+fn verify_timed<T: Verifiable>(tx: &T) -> (bool, u64) {
+    let start = Instant::now();
+    let ok = tx.verify();
+    (ok, start.elapsed().as_nanos() as u64)
+}
+
+/// Time a whole run; the histogram is built after the clock stops
+fn timed(run: impl FnOnce() -> Vec<(bool, u64)>) -> StrategyOutcome {
+    let start = Instant::now();
+    let results = run();
+    let duration = start.elapsed();
+    outcome(results, duration, None)
+}
+
+fn outcome(results: Vec<(bool, u64)>, duration: Duration, mesh: Option<MeshStats>) -> StrategyOutcome {
+    StrategyOutcome {
+        verified: results.iter().filter(|(ok, _)| *ok).count(),
+        duration,
+        latency: LatencyHistogram::from_nanos(results.iter().map(|(_, nanos)| *nanos)),
+        mesh,
+    }
+}
+
+/// Single-threaded baseline
+pub struct Sequential;
+
+impl<T: Verifiable> VerificationStrategy<T> for Sequential {
+    fn name(&self) -> String {
+        "Sequential".to_string()
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        timed(|| items.iter().map(verify_timed).collect())
+    }
+}
+
+/// `par_iter()` over the whole set, leaving balancing to rayon's work-stealing
+pub struct FlatRayon;
+
+impl<T: Verifiable> VerificationStrategy<T> for FlatRayon {
+    fn name(&self) -> String {
+        "Flat rayon".to_string()
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        timed(|| items.par_iter().map(verify_timed).collect())
+    }
+}
+
+/// Flat rayon on a dedicated pool of `threads` workers instead of the global pool
+pub struct RayonPool {
+    threads: usize,
+    pool: rayon::ThreadPool,
+}
+
+impl RayonPool {
+    pub fn new(threads: usize) -> Result<Self, rayon::ThreadPoolBuildError> {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads.max(1)).build()?;
+        Ok(Self { threads: threads.max(1), pool })
+    }
+}
+
+impl<T: Verifiable> VerificationStrategy<T> for RayonPool {
+    fn name(&self) -> String {
+        format!("Rayon ×{}", self.threads)
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        self.pool.install(|| FlatRayon.run(items))
+    }
+}
+
+/// Bucket by `segment_id % segments`, then verify each bucket as one rayon task
+pub struct StaticSegments {
+    pub segments: usize,
+}
+
+impl<T: Verifiable> VerificationStrategy<T> for StaticSegments {
+    fn name(&self) -> String {
+        format!("Static {} seg", self.segments)
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        // Partitioning is timed, as the torus times building its queues
+        timed(|| {
+            let mut buckets: Vec<Vec<&T>> = vec![Vec::new(); self.segments];
+            for tx in items {
+                buckets[tx.segment_id() % self.segments].push(tx);
+            }
+            buckets
+                .par_iter()
+                .flat_map_iter(|segment| segment.iter().map(|tx| verify_timed(*tx)).collect::<Vec<_>>())
+                .collect()
+        })
+    }
+}
+
+/// Contiguous chunks of the input, one rayon task per chunk
+pub struct Chunked {
+    pub chunks: usize,
+}
+
+impl<T: Verifiable> VerificationStrategy<T> for Chunked {
+    fn name(&self) -> String {
+        format!("Chunked ×{}", self.chunks)
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        let chunk_size = items.len().div_ceil(self.chunks).max(1);
+        timed(|| {
+            items
+                .par_chunks(chunk_size)
+                .flat_map_iter(|chunk| chunk.iter().map(verify_timed).collect::<Vec<_>>())
+                .collect()
+        })
+    }
+}
+
+/// 3D torus with neighbor work-stealing (see [`TorusMesh`])
+pub struct Torus {
+    pub mesh: TorusMesh,
+}
+
+impl<T: Verifiable> VerificationStrategy<T> for Torus {
+    fn name(&self) -> String {
+        format!("Torus {}", self.mesh)
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        let start = Instant::now();
+        let (results, stats) = self.mesh.run(items, |tx| tx.segment_id(), verify_timed);
+        outcome(results, start.elapsed(), Some(stats))
+    }
+}
+
+/// Fixed number of OS threads pulling the next index from a shared counter
+pub struct ThreadPool {
+    threads: usize,
+}
+
+impl ThreadPool {
+    pub fn new(threads: usize) -> Self {
+        Self { threads: threads.max(1) }
+    }
+}
+
+impl<T: Verifiable> VerificationStrategy<T> for ThreadPool {
+    fn name(&self) -> String {
+        format!("Thread pool ×{}", self.threads)
+    }
+
+    fn run(&self, items: &[T]) -> StrategyOutcome {
+        timed(|| {
+            let next = AtomicUsize::new(0);
+            std::thread::scope(|scope| {
+                let workers: Vec<_> = (0..self.threads)
+                    .map(|_| {
+                        scope.spawn(|| {
+                            let mut results = Vec::new();
+                            loop {
+                                let i = next.fetch_add(1, Ordering::Relaxed);
+                                let Some(tx) = items.get(i) else { break };
+                                results.push(verify_timed(tx));
+                            }
+                            results
+                        })
+                    })
+                    .collect();
+                workers
+                    .into_iter()
+                    .flat_map(|worker| worker.join().expect("verification worker panicked"))
+                    .collect()
+            })
+        })
+    }
+}
@@ -0,0 +1,380 @@
+//! Direct transaction submission
+//!
+//! `--real-tps --direct` skips the faucet: transfers are signed locally (see
+//! [`ExtrinsicFormat`](crate::extrinsic::ExtrinsicFormat)) and submitted with
+//! `author_submitAndWatchExtrinsic` over WebSocket. Extrinsics are dealt
+//! round-robin over `connections` connections to each online validator, so
+//! consecutive nonces land on different nodes and up to validators ×
+//! connections submissions are in flight at once.
+//!
+//! The node answers each submission with a subscription once the transaction
+//! is in its pool, or with an error saying why it was rejected: a bad
+//! signature or proof, a stale nonce, no funds for the fee, a full pool.
+//! Submissions that never got an answer (connection refused or dropped, or a
+//! node that stayed silent) are counted apart as failed. Each connection then
+//! follows the status events of the transactions it submitted (see
+//! [`crate::inclusion`]).
+//!
+//! Connections either submit one transaction at a time, or, for `--load`,
+//! send each at its scheduled time without waiting for answers (see
+//! [`crate::load`]).
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+
+use crate::extrinsic::{blake2_256, ChainInfo};
+use crate::inclusion::{TxStatus, TxTrace};
+use crate::ws::{self, Incoming, WsClient};
+
+/// What the node made of one submission
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    /// In the pool, with what happened to it afterwards
+    Accepted(TxTrace),
+    /// Refused by the node, with its reason
+    Rejected(String),
+    /// Sent, but the node never answered; the trace has no status events
+    Unanswered(TxTrace),
+    /// Not sent, or the connection was lost
+    Failed(String),
+}
+
+/// Reason recorded for [`Outcome::Unanswered`]
+pub const NOT_ANSWERED: &str = "not answered";
+
+/// Submission counts for one validator
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatorSubmissions {
+    pub url: String,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub failed: usize,
+}
+
+/// How many submissions were rejected or failed for one reason
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RejectionReason {
+    pub reason: String,
+    pub count: usize,
+}
+
+/// A `--direct` run: what was signed and what the validators answered
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DirectSubmission {
+    pub scheme: String,
+    /// SS58 address of the signing account
+    pub signer: String,
+    pub first_nonce: u64,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    /// Encoded size of one extrinsic
+    pub extrinsic_bytes: usize,
+    pub sign_secs: f64,
+    /// WebSocket connections per validator
+    pub connections: usize,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub failed: usize,
+    pub validators: Vec<ValidatorSubmissions>,
+    /// Rejection and failure reasons, most frequent first
+    pub reasons: Vec<RejectionReason>,
+}
+
+impl DirectSubmission {
+    /// Count the outcomes of [`submit_all`] against `validators`
+    pub fn record(&mut self, validators: &[String], outcomes: &[(usize, Outcome)]) {
+        self.validators = validators
+            .iter()
+            .map(|url| ValidatorSubmissions { url: url.clone(), accepted: 0, rejected: 0, failed: 0 })
+            .collect();
+        let mut reasons: HashMap<&str, usize> = HashMap::new();
+        for (validator, outcome) in outcomes {
+            let counts = &mut self.validators[*validator];
+            match outcome {
+                Outcome::Accepted(_) => counts.accepted += 1,
+                Outcome::Rejected(reason) => {
+                    counts.rejected += 1;
+                    *reasons.entry(reason).or_default() += 1;
+                }
+                Outcome::Unanswered(_) => {
+                    counts.failed += 1;
+                    *reasons.entry(NOT_ANSWERED).or_default() += 1;
+                }
+                Outcome::Failed(reason) => {
+                    counts.failed += 1;
+                    *reasons.entry(reason).or_default() += 1;
+                }
+            }
+        }
+        self.accepted = self.validators.iter().map(|v| v.accepted).sum();
+        self.rejected = self.validators.iter().map(|v| v.rejected).sum();
+        self.failed = self.validators.iter().map(|v| v.failed).sum();
+        self.reasons = reasons
+            .into_iter()
+            .map(|(reason, count)| RejectionReason { reason: reason.to_string(), count })
+            .collect();
+        self.reasons.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.reason.cmp(&b.reason)));
+    }
+}
+
+/// Genesis hash and runtime versions of the chain behind `client`
+pub async fn chain_info(client: &mut WsClient) -> Result<ChainInfo, String> {
+    let genesis = client.request("chain_getBlockHash", vec![0.into()]).await?;
+    let genesis = genesis.as_str().ok_or("chain_getBlockHash: no genesis hash")?;
+    let genesis_hash = hex::decode(genesis.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("invalid genesis hash '{}'", genesis))?;
+
+    let version = client.request("state_getRuntimeVersion", vec![]).await?;
+    let field = |name: &str| {
+        version
+            .get(name)
+            .and_then(Value::as_u64)
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| format!("state_getRuntimeVersion: no {}", name))
+    };
+    Ok(ChainInfo {
+        genesis_hash,
+        spec_version: field("specVersion")?,
+        transaction_version: field("transactionVersion")?,
+    })
+}
+
+/// The next nonce of `address`, counting transactions already in the pool
+pub async fn next_nonce(client: &mut WsClient, address: &str) -> Result<u64, String> {
+    client
+        .request("system_accountNextIndex", vec![address.into()])
+        .await?
+        .as_u64()
+        .ok_or_else(|| "system_accountNextIndex: no nonce".to_string())
+}
+
+/// How long a connection waits on a silent node for an answer to its last submission
+const ANSWER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// One extrinsic on its way to a validator
+struct Pending {
+    /// Position in the `submit_all` input
+    index: usize,
+    /// Scheduled send time in seconds since the start, `None` to send once the previous one is answered
+    send_at: Option<f64>,
+    extrinsic: Vec<u8>,
+}
+
+/// Submit encoded extrinsics over `connections` connections to each of `validators`
+///
+/// With `send_at` (one time per extrinsic, in seconds since `start`) every
+/// extrinsic goes out on schedule without waiting for earlier answers;
+/// without, each connection submits one at a time. Each connection follows
+/// its accepted transactions until they are final or `confirm` after its last
+/// submission; a node that leaves a submission unanswered gets at least
+/// [`ANSWER_TIMEOUT`] after it. Returns, per extrinsic, the index of the
+/// validator it went to and the outcome, and when the last submission was
+/// answered, in seconds since `start`.
+pub async fn submit_all(
+    validators: &[String],
+    extrinsics: &[Vec<u8>],
+    send_at: Option<&[f64]>,
+    connections: usize,
+    start: Instant,
+    confirm: Duration,
+) -> (Vec<(usize, Outcome)>, f64) {
+    let slots = validators.len() * connections.max(1);
+    let mut tasks = JoinSet::new();
+    for slot in 0..slots.min(extrinsics.len()) {
+        let validator = slot % validators.len();
+        let url = validators[validator].clone();
+        let share: Vec<Pending> = (slot..extrinsics.len())
+            .step_by(slots)
+            .map(|index| Pending {
+                index,
+                send_at: send_at.map(|times| times[index]),
+                extrinsic: extrinsics[index].clone(),
+            })
+            .collect();
+        tasks.spawn(async move { (validator, submit_share(&url, share, start, confirm).await) });
+    }
+
+    let mut outcomes = vec![(0, Outcome::Failed("not submitted".to_string())); extrinsics.len()];
+    let mut submission_secs: f64 = 0.0;
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((validator, (results, answered))) = joined {
+            for (index, outcome) in results {
+                outcomes[index] = (validator, outcome);
+            }
+            submission_secs = submission_secs.max(answered);
+        }
+    }
+    (outcomes, submission_secs)
+}
+
+/// Submit `share` on a single connection to `url`, then follow the accepted transactions
+async fn submit_share(
+    url: &str,
+    share: Vec<Pending>,
+    start: Instant,
+    confirm: Duration,
+) -> (Vec<(usize, Outcome)>, f64) {
+    let mut client = match WsClient::connect(url).await {
+        Ok(client) => client,
+        Err(e) => {
+            let outcomes = share.into_iter().map(|p| (p.index, Outcome::Failed(e.clone()))).collect();
+            return (outcomes, start.elapsed().as_secs_f64());
+        }
+    };
+    let mut outcomes: Vec<(usize, Outcome)> =
+        share.iter().map(|p| (p.index, Outcome::Failed("not sent".to_string()))).collect();
+    let mut sent_secs = vec![0.0; share.len()];
+    // Call id, then subscription id, to position in `share`
+    let mut awaiting: HashMap<u64, usize> = HashMap::new();
+    let mut watching: HashMap<String, usize> = HashMap::new();
+    let mut next = 0;
+    let mut answered = start.elapsed().as_secs_f64();
+    let mut last_sent = tokio::time::Instant::now();
+    let mut deadline = None;
+    let never = tokio::time::Instant::now() + Duration::from_secs(365 * 24 * 3600);
+    let hash = |pending: &Pending| format!("0x{}", hex::encode(blake2_256(&pending.extrinsic)));
+
+    loop {
+        if next == share.len() {
+            if awaiting.is_empty() && watching.is_empty() {
+                break;
+            }
+            deadline.get_or_insert_with(|| tokio::time::Instant::now() + confirm);
+        }
+        // On schedule, or as soon as the previous submission is answered
+        let due = share.get(next).and_then(|p| match p.send_at {
+            Some(at) => Some(tokio::time::Instant::from_std(start + Duration::from_secs_f64(at))),
+            None if awaiting.is_empty() => Some(tokio::time::Instant::now()),
+            None => None,
+        });
+        // Unanswered calls stay unanswered once a silent node has had its time
+        let answers_by = (!awaiting.is_empty()).then(|| last_sent + ANSWER_TIMEOUT);
+        let give_up = match (deadline, answers_by) {
+            (Some(deadline), Some(answers_by)) => Some(deadline.max(answers_by)),
+            (Some(deadline), None) => Some(deadline),
+            (None, answers_by) if due.is_none() => answers_by,
+            (None, _) => None,
+        };
+
+        tokio::select! {
+            _ = tokio::time::sleep_until(due.unwrap_or(never)), if due.is_some() => {
+                let params = vec![format!("0x{}", hex::encode(&share[next].extrinsic)).into()];
+                sent_secs[next] = start.elapsed().as_secs_f64();
+                last_sent = tokio::time::Instant::now();
+                match client.send("author_submitAndWatchExtrinsic", params).await {
+                    Ok(id) => {
+                        awaiting.insert(id, next);
+                        let mut trace = TxTrace::new(hash(&share[next]), sent_secs[next]);
+                        trace.status = NOT_ANSWERED.to_string();
+                        outcomes[next].1 = Outcome::Unanswered(trace);
+                    }
+                    Err(e) => outcomes[next].1 = Outcome::Failed(e),
+                }
+                next += 1;
+            }
+            incoming = client.next_incoming() => match incoming {
+                Ok(Incoming::Response { id, result }) => {
+                    let Some(position) = awaiting.remove(&id) else { continue };
+                    answered = start.elapsed().as_secs_f64();
+                    outcomes[position].1 = match result {
+                        Ok(subscription) => {
+                            if let Some(subscription) = ws::subscription_id(&subscription) {
+                                watching.insert(subscription, position);
+                            }
+                            Outcome::Accepted(TxTrace::new(hash(&share[position]), sent_secs[position]))
+                        }
+                        Err(error) => Outcome::Rejected(error.to_string()),
+                    };
+                }
+                Ok(Incoming::Notification(notification)) => {
+                    let Some(&position) = watching.get(&notification.subscription) else { continue };
+                    let Some(status) = TxStatus::parse(&notification.result) else { continue };
+                    if let (_, Outcome::Accepted(trace)) = &mut outcomes[position] {
+                        trace.apply(&status, notification.received.duration_since(start).as_secs_f64());
+                    }
+                    if status.is_final() {
+                        watching.remove(&notification.subscription);
+                    }
+                }
+                // The connection is gone: whatever was not answered yet never will be
+                Err(e) => {
+                    for &position in awaiting.values() {
+                        outcomes[position].1 = Outcome::Failed(e.clone());
+                    }
+                    for outcome in &mut outcomes[next..] {
+                        outcome.1 = Outcome::Failed(e.clone());
+                    }
+                    break;
+                }
+            },
+            _ = tokio::time::sleep_until(give_up.unwrap_or(never)), if give_up.is_some() => {
+                for outcome in &mut outcomes[next..] {
+                    outcome.1 = Outcome::Failed("not sent: an earlier submission was never answered".to_string());
+                }
+                break;
+            }
+        }
+    }
+    client.close().await;
+    (outcomes, answered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+    use tokio::net::TcpListener;
+
+    /// A WebSocket server that reads every call and never answers one
+    async fn silent_node() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    while let Some(Ok(_)) = socket.next().await {}
+                });
+            }
+        });
+        url
+    }
+
+    /// Submit to a silent node with the clock paused, failing if that takes more than a few real seconds
+    fn submit_to_silent_node(count: u8, send_at: Option<Vec<f64>>, confirm: Duration) -> Vec<(usize, Outcome)> {
+        let (done, outcomes) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().start_paused(true).build().unwrap();
+            let (submitted, _) = runtime.block_on(async move {
+                let validators = vec![silent_node().await];
+                let extrinsics: Vec<Vec<u8>> = (0..count).map(|i| vec![i]).collect();
+                submit_all(&validators, &extrinsics, send_at.as_deref(), 1, Instant::now(), confirm).await
+            });
+            let _ = done.send(submitted);
+        });
+        outcomes.recv_timeout(Duration::from_secs(10)).expect("submission hung")
+    }
+
+    fn not_answered(outcome: &Outcome) -> bool {
+        matches!(outcome, Outcome::Unanswered(trace) if trace.status == NOT_ANSWERED && trace.in_block_secs.is_none())
+    }
+
+    #[test]
+    fn silent_node_does_not_hang_pipelined_submission() {
+        let outcomes = submit_to_silent_node(3, Some(vec![0.0, 0.0, 0.0]), Duration::from_secs(5));
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(|(_, outcome)| not_answered(outcome)));
+    }
+
+    #[test]
+    fn silent_node_does_not_hang_sequential_submission() {
+        let outcomes = submit_to_silent_node(2, None, Duration::ZERO);
+        assert!(not_answered(&outcomes[0].1));
+        assert!(matches!(&outcomes[1].1, Outcome::Failed(reason) if reason.starts_with("not sent")));
+    }
+}
@@ -0,0 +1,150 @@
+//! Segment-count sweeps
+//!
+//! Which segment counts the torus sweep measures, and which thread counts
+//! `--thread-sweep` measures. A sweep is written as one of:
+//!
+//! - a list: `2,4,8,48`
+//! - an inclusive range with an optional step: `8..64:8`
+//! - powers of two up to N: `pow2:512` (1, 2, 4, …, 512)
+//! - multiples of the logical core count: `cores:4` (1×, 2×, 3×, 4× cores)
+//!
+//! The same strings work in scenario files, where a plain TOML list of
+//! integers is also accepted.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SweepSpec {
+    List(Vec<usize>),
+    Range { start: usize, end: usize, step: usize },
+    PowersOfTwo { max: usize },
+    CoreMultiples { max_multiple: usize },
+}
+
+impl SweepSpec {
+    /// The values to run, in order, without duplicates
+    pub fn values(&self, cores: usize) -> Vec<usize> {
+        let values: Vec<usize> = match *self {
+            SweepSpec::List(ref list) => list.clone(),
+            SweepSpec::Range { start, end, step } => (start..=end).step_by(step).collect(),
+            SweepSpec::PowersOfTwo { max } => (0..usize::BITS).map(|i| 1usize << i).take_while(|&s| s <= max).collect(),
+            SweepSpec::CoreMultiples { max_multiple } => (1..=max_multiple).map(|m| m * cores.max(1)).collect(),
+        };
+        let mut unique = Vec::with_capacity(values.len());
+        for v in values {
+            if !unique.contains(&v) {
+                unique.push(v);
+            }
+        }
+        unique
+    }
+}
+
+impl FromStr for SweepSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let number = |v: &str| -> Result<usize, String> {
+            match v.trim().parse::<usize>() {
+                Ok(0) => Err(format!("sweep values must be at least 1 in '{}'", s)),
+                Ok(n) => Ok(n),
+                Err(e) => Err(format!("invalid number '{}' in sweep '{}': {}", v.trim(), s, e)),
+            }
+        };
+
+        if let Some(max) = s.strip_prefix("pow2:") {
+            return Ok(SweepSpec::PowersOfTwo { max: number(max)? });
+        }
+        if let Some(multiples) = s.strip_prefix("cores:") {
+            return Ok(SweepSpec::CoreMultiples { max_multiple: number(multiples)? });
+        }
+        if let Some((start, rest)) = s.split_once("..") {
+            let (end, step) = rest.split_once(':').unwrap_or((rest, "1"));
+            let (start, end, step) = (number(start)?, number(end.trim_start_matches('='))?, number(step)?);
+            if start > end {
+                return Err(format!("sweep range '{}' is empty", s));
+            }
+            return Ok(SweepSpec::Range { start, end, step });
+        }
+        let list = s.split(',').map(number).collect::<Result<Vec<_>, _>>()?;
+        Ok(SweepSpec::List(list))
+    }
+}
+
+impl fmt::Display for SweepSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SweepSpec::List(list) => {
+                let values: Vec<String> = list.iter().map(|v| v.to_string()).collect();
+                f.write_str(&values.join(","))
+            }
+            SweepSpec::Range { start, end, step: 1 } => write!(f, "{}..{}", start, end),
+            SweepSpec::Range { start, end, step } => write!(f, "{}..{}:{}", start, end, step),
+            SweepSpec::PowersOfTwo { max } => write!(f, "pow2:{}", max),
+            SweepSpec::CoreMultiples { max_multiple } => write!(f, "cores:{}", max_multiple),
+        }
+    }
+}
+
+impl Serialize for SweepSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SweepSpec {
+    /// A spec string, or a plain list of segment counts
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            List(Vec<usize>),
+            Spec(String),
+        }
+        match Raw::deserialize(deserializer)? {
+            Raw::List(list) if list.is_empty() => Err(serde::de::Error::custom("sweep list is empty")),
+            Raw::List(list) if list.contains(&0) => Err(serde::de::Error::custom("sweep values must be at least 1")),
+            Raw::List(list) => Ok(SweepSpec::List(list)),
+            Raw::Spec(spec) => spec.parse().map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(spec: &str, cores: usize) -> Vec<usize> {
+        spec.parse::<SweepSpec>().unwrap().values(cores)
+    }
+
+    #[test]
+    fn powers_of_two_start_at_one() {
+        assert_eq!(values("pow2:1", 8), vec![1]);
+        assert_eq!(values("pow2:64", 8), vec![1, 2, 4, 8, 16, 32, 64]);
+        assert_eq!(values("pow2:48", 8), vec![1, 2, 4, 8, 16, 32]);
+    }
+
+    #[test]
+    fn core_multiples_scale_with_cores() {
+        assert_eq!(values("cores:4", 6), vec![6, 12, 18, 24]);
+        assert_eq!(values("cores:2", 0), vec![1, 2]);
+    }
+
+    #[test]
+    fn ranges_honor_the_step() {
+        assert_eq!(values("8..64:8", 1), vec![8, 16, 24, 32, 40, 48, 56, 64]);
+        assert_eq!(values("1..10:4", 1), vec![1, 5, 9]);
+        assert_eq!(values("3..5", 1), vec![3, 4, 5]);
+        assert!("9..3".parse::<SweepSpec>().is_err());
+    }
+
+    #[test]
+    fn lists_keep_order_and_drop_duplicates() {
+        assert_eq!(values("8,2,8,4", 1), vec![8, 2, 4]);
+        assert!("2,0".parse::<SweepSpec>().is_err());
+    }
+}
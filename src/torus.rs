@@ -0,0 +1,230 @@
+//! 3D toroidal mesh scheduler
+//!
+//! Segments are laid out on an X×Y×Z torus. Each segment owns a queue of
+//! transactions; when its own queue runs dry it steals from the back of its
+//! six torus neighbors (±1 in each dimension, wrapping around). Segments are
+//! executed as rayon tasks, so the mesh decides *which* work a segment does
+//! while rayon decides *when* a segment runs.
+
+use rayon::prelude::*;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Topology of a 3D torus of verification segments
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TorusMesh {
+    dims: [usize; 3],
+}
+
+/// What happened on each segment during one mesh run
+#[derive(Clone, Debug)]
+pub struct MeshStats {
+    pub dims: [usize; 3],
+    /// Items processed by each segment (own queue plus stolen)
+    pub load: Vec<usize>,
+    /// Items each segment stole from its neighbors
+    pub steals: Vec<usize>,
+}
+
+impl TorusMesh {
+    pub fn new(dims: [usize; 3]) -> Self {
+        assert!(dims.iter().all(|&d| d > 0), "torus dimensions must be non-zero");
+        Self { dims }
+    }
+
+    /// The most cube-like torus with exactly `segments` segments
+    /// (512 → 8×8×8, 64 → 4×4×4, 32 → 4×4×2)
+    pub fn for_segments(segments: usize) -> Self {
+        let segments = segments.max(1);
+        let mut best = [segments, 1, 1];
+        for x in 1..=segments {
+            if !segments.is_multiple_of(x) {
+                continue;
+            }
+            for y in 1..=x {
+                if !(segments / x).is_multiple_of(y) {
+                    continue;
+                }
+                let z = segments / x / y;
+                if z > y {
+                    continue;
+                }
+                if x - z < best[0] - best[2] {
+                    best = [x, y, z];
+                }
+            }
+        }
+        Self::new(best)
+    }
+
+    pub fn dims(&self) -> [usize; 3] {
+        self.dims
+    }
+
+    pub fn segments(&self) -> usize {
+        self.dims.iter().product()
+    }
+
+    fn coords(&self, id: usize) -> [usize; 3] {
+        let [x, y, _] = self.dims;
+        [id % x, (id / x) % y, id / (x * y)]
+    }
+
+    fn id(&self, [cx, cy, cz]: [usize; 3]) -> usize {
+        let [x, y, _] = self.dims;
+        cx + cy * x + cz * x * y
+    }
+
+    /// Distinct torus neighbors of a segment (up to six, never itself)
+    pub fn neighbors(&self, id: usize) -> Vec<usize> {
+        let coords = self.coords(id);
+        let mut neighbors = Vec::with_capacity(6);
+        for axis in 0..3 {
+            let size = self.dims[axis];
+            for step in [1, size - 1] {
+                let mut c = coords;
+                c[axis] = (c[axis] + step) % size;
+                let n = self.id(c);
+                if n != id && !neighbors.contains(&n) {
+                    neighbors.push(n);
+                }
+            }
+        }
+        neighbors
+    }
+
+    /// Process `items` on the mesh. `assign` picks each item's home segment
+    /// (taken modulo the segment count). Returns every `work` result, grouped
+    /// by the segment that produced it, and per-segment statistics.
+    pub fn run<T, R, A, W>(&self, items: &[T], assign: A, work: W) -> (Vec<R>, MeshStats)
+    where
+        T: Sync,
+        R: Send,
+        A: Fn(&T) -> usize,
+        W: Fn(&T) -> R + Sync,
+    {
+        let segments = self.segments();
+        let mut queues: Vec<VecDeque<usize>> = vec![VecDeque::new(); segments];
+        for (i, item) in items.iter().enumerate() {
+            queues[assign(item) % segments].push_back(i);
+        }
+        let queues: Vec<Mutex<VecDeque<usize>>> = queues.into_iter().map(Mutex::new).collect();
+
+        let per_segment: Vec<(Vec<R>, usize)> = (0..segments)
+            .into_par_iter()
+            .map(|seg| {
+                let neighbors = self.neighbors(seg);
+                let (mut results, mut steals) = (Vec::new(), 0);
+                loop {
+                    // Own queue first (front), then steal from a neighbor's back
+                    let next = queues[seg].lock().unwrap().pop_front().or_else(|| {
+                        let stolen = neighbors.iter().find_map(|&n| queues[n].lock().unwrap().pop_back());
+                        steals += stolen.is_some() as usize;
+                        stolen
+                    });
+                    let Some(i) = next else { break };
+                    results.push(work(&items[i]));
+                }
+                (results, steals)
+            })
+            .collect();
+
+        let stats = MeshStats {
+            dims: self.dims,
+            load: per_segment.iter().map(|s| s.0.len()).collect(),
+            steals: per_segment.iter().map(|s| s.1).collect(),
+        };
+        let results = per_segment.into_iter().flat_map(|s| s.0).collect();
+        (results, stats)
+    }
+}
+
+impl FromStr for TorusMesh {
+    type Err = String;
+
+    /// Parse `XxYxZ`, e.g. `8x8x8`
+    fn from_str(s: &str) -> Result<Self, String> {
+        let dims: Vec<usize> = s
+            .split(['x', 'X', '×'])
+            .map(|d| d.trim().parse::<usize>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| format!("invalid torus dimensions '{}': {}", s, e))?;
+        match dims.as_slice() {
+            [x, y, z] if *x > 0 && *y > 0 && *z > 0 => Ok(Self::new([*x, *y, *z])),
+            _ => Err(format!("expected three non-zero dimensions like 8x8x8, got '{}'", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for TorusMesh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [x, y, z] = self.dims;
+        write!(f, "{}×{}×{}", x, y, z)
+    }
+}
+
+impl MeshStats {
+    pub fn total_steals(&self) -> usize {
+        self.steals.iter().sum()
+    }
+
+    pub fn max_load(&self) -> usize {
+        self.load.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn min_load(&self) -> usize {
+        self.load.iter().copied().min().unwrap_or(0)
+    }
+
+    pub fn mean_load(&self) -> f64 {
+        self.load.iter().sum::<usize>() as f64 / self.load.len().max(1) as f64
+    }
+
+    /// Busiest segment relative to the mean (1.0 = perfectly balanced)
+    pub fn imbalance(&self) -> f64 {
+        let mean = self.mean_load();
+        if mean > 0.0 {
+            self.max_load() as f64 / mean
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_item_is_processed_exactly_once() {
+        for dims in [[1, 1, 1], [2, 1, 1], [4, 4, 2], [8, 8, 8]] {
+            let mesh = TorusMesh::new(dims);
+            let items: Vec<usize> = (0..1000).collect();
+            // Everything homed on segment 0, so the rest of the mesh has to steal it
+            let (mut results, stats) = mesh.run(&items, |_| 0, |&i| i);
+            results.sort_unstable();
+            assert_eq!(results, items, "{:?}", dims);
+            assert_eq!(stats.load.iter().sum::<usize>(), items.len());
+            assert_eq!(stats.load.len(), mesh.segments());
+        }
+    }
+
+    #[test]
+    fn neighbors_wrap_around_and_exclude_self() {
+        let mesh = TorusMesh::new([4, 4, 4]);
+        let mut neighbors = mesh.neighbors(0);
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, vec![1, 3, 4, 12, 16, 48]);
+        assert!(TorusMesh::new([1, 1, 1]).neighbors(0).is_empty());
+        assert_eq!(TorusMesh::new([2, 1, 1]).neighbors(0), vec![1]);
+    }
+
+    #[test]
+    fn for_segments_picks_the_most_cube_like_shape() {
+        assert_eq!(TorusMesh::for_segments(512).dims(), [8, 8, 8]);
+        assert_eq!(TorusMesh::for_segments(64).dims(), [4, 4, 4]);
+        assert_eq!(TorusMesh::for_segments(32).dims(), [4, 4, 2]);
+        assert_eq!(TorusMesh::for_segments(7).dims(), [7, 1, 1]);
+    }
+}
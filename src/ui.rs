@@ -0,0 +1,52 @@
+//! Human-readable output routing
+//!
+//! Progress lines and tables normally go to stdout. When stdout carries a
+//! machine-readable report (`--output json|csv` without `--out`), they are
+//! sent to stderr instead so the report can be piped straight into CI.
+
+use std::fmt;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// Send human-readable output to stderr from now on
+pub fn route_to_stderr() {
+    TO_STDERR.store(true, Ordering::Relaxed);
+}
+
+pub fn write(args: fmt::Arguments) {
+    if TO_STDERR.load(Ordering::Relaxed) {
+        let _ = std::io::stderr().write_fmt(args);
+    } else {
+        let _ = std::io::stdout().write_fmt(args);
+    }
+}
+
+pub fn flush() {
+    if TO_STDERR.load(Ordering::Relaxed) {
+        let _ = std::io::stderr().flush();
+    } else {
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// `print!` for human-readable output
+macro_rules! say {
+    ($($arg:tt)*) => {
+        $crate::ui::write(format_args!($($arg)*))
+    };
+}
+
+/// `println!` for human-readable output
+macro_rules! sayln {
+    () => {
+        $crate::ui::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {{
+        $crate::ui::write(format_args!($($arg)*));
+        $crate::ui::write(format_args!("\n"));
+    }};
+}
+
+pub(crate) use {say, sayln};
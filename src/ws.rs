@@ -0,0 +1,206 @@
+//! WebSocket JSON-RPC client
+//!
+//! Substrate nodes serve JSON-RPC over WebSocket on the same port as HTTP,
+//! and subscriptions (`chain_subscribeNewHeads`, `author_submitAndWatchExtrinsic`)
+//! are only available there. One connection carries requests and any number
+//! of subscriptions; notifications that arrive while waiting for a response
+//! are buffered and handed out by [`WsClient::next_notification`] in order.
+//! Calls can also be pipelined: [`WsClient::send`] returns at once and
+//! [`WsClient::next_incoming`] hands out answers and notifications as they
+//! arrive.
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::time::Instant;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// One subscription notification
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// Subscription id returned by [`WsClient::subscribe`]
+    pub subscription: String,
+    pub result: Value,
+    /// When it came off the socket, which can be well before it is handed out
+    pub received: Instant,
+}
+
+/// An error object returned by the node
+#[derive(Clone, Debug)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    /// Detail, e.g. why a transaction is invalid
+    pub data: Option<String>,
+}
+
+impl std::fmt::Display for RpcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.data {
+            Some(data) => write!(f, "{}: {}", self.message, data),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl RpcError {
+    fn from_value(error: &Value) -> Self {
+        Self {
+            code: error.get("code").and_then(Value::as_i64).unwrap_or(0),
+            message: error.get("message").and_then(Value::as_str).unwrap_or("unknown error").to_string(),
+            data: error.get("data").map(|data| match data {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            }),
+        }
+    }
+}
+
+/// A message from the node
+#[derive(Clone, Debug)]
+pub enum Incoming {
+    /// The answer to the call sent with this id
+    Response { id: u64, result: Result<Value, RpcError> },
+    Notification(Notification),
+}
+
+pub struct WsClient {
+    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    next_id: u64,
+    notifications: VecDeque<Notification>,
+}
+
+/// A subscription id, which nodes send as a string or a number
+pub fn subscription_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(id) => Some(id.clone()),
+        Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    }
+}
+
+/// The WebSocket endpoint for an RPC URL: `http://` becomes `ws://`, `https://` becomes `wss://`
+pub fn ws_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else {
+        url.to_string()
+    }
+}
+
+impl WsClient {
+    /// Connect to `url` (`ws://`, `wss://`, or an `http(s)://` RPC URL)
+    pub async fn connect(url: &str) -> Result<Self, String> {
+        let (stream, _) = tokio_tungstenite::connect_async(ws_url(url))
+            .await
+            .map_err(|e| format!("WebSocket connection failed: {}", e))?;
+        Ok(Self { stream, next_id: 1, notifications: VecDeque::new() })
+    }
+
+    /// Make one call and wait for its result
+    pub async fn request(&mut self, method: &str, params: Vec<Value>) -> Result<Value, String> {
+        self.try_request(method, params).await?.map_err(|e| format!("{}: {}", method, e))
+    }
+
+    /// Make one call, keeping an error returned by the node apart from a failed connection
+    pub async fn try_request(&mut self, method: &str, params: Vec<Value>) -> Result<Result<Value, RpcError>, String> {
+        let id = self.send(method, params).await?;
+        loop {
+            let message = self.read().await?;
+            if message.get("id").and_then(Value::as_u64) == Some(id) {
+                if let Some(error) = message.get("error") {
+                    return Ok(Err(RpcError::from_value(error)));
+                }
+                return message.get("result").cloned().map(Ok).ok_or_else(|| format!("{}: no result", method));
+            }
+            self.buffer(message);
+        }
+    }
+
+    /// Send a call without waiting for its answer, returning its id
+    ///
+    /// Answers come back through [`WsClient::next_incoming`].
+    pub async fn send(&mut self, method: &str, params: Vec<Value>) -> Result<u64, String> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.stream
+            .send(Message::Text(request.to_string()))
+            .await
+            .map_err(|e| format!("WebSocket send failed: {}", e))?;
+        Ok(id)
+    }
+
+    /// The next answer or notification, in arrival order
+    pub async fn next_incoming(&mut self) -> Result<Incoming, String> {
+        loop {
+            if let Some(notification) = self.notifications.pop_front() {
+                return Ok(Incoming::Notification(notification));
+            }
+            let message = self.read().await?;
+            if let Some(id) = message.get("id").and_then(Value::as_u64) {
+                let result = match message.get("error") {
+                    Some(error) => Err(RpcError::from_value(error)),
+                    None => Ok(message.get("result").cloned().unwrap_or(Value::Null)),
+                };
+                return Ok(Incoming::Response { id, result });
+            }
+            self.buffer(message);
+        }
+    }
+
+    /// Start a subscription, returning its id
+    pub async fn subscribe(&mut self, method: &str, params: Vec<Value>) -> Result<String, String> {
+        let id = self.request(method, params).await?;
+        subscription_id(&id).ok_or_else(|| format!("{}: unexpected subscription id {}", method, id))
+    }
+
+    /// The next notification from any subscription on this connection
+    pub async fn next_notification(&mut self) -> Result<Notification, String> {
+        loop {
+            if let Some(notification) = self.notifications.pop_front() {
+                return Ok(notification);
+            }
+            let message = self.read().await?;
+            self.buffer(message);
+        }
+    }
+
+    /// Keep `message` if it is a subscription notification
+    fn buffer(&mut self, message: Value) {
+        let Some(params) = message.get("params") else { return };
+        let Some(subscription) = params.get("subscription").and_then(subscription_id) else { return };
+        let result = params.get("result").cloned().unwrap_or(Value::Null);
+        self.notifications.push_back(Notification { subscription, result, received: Instant::now() });
+    }
+
+    /// Read the next JSON-RPC message
+    async fn read(&mut self) -> Result<Value, String> {
+        loop {
+            let message = self
+                .stream
+                .next()
+                .await
+                .ok_or("WebSocket closed")?
+                .map_err(|e| format!("WebSocket read failed: {}", e))?;
+            match message {
+                Message::Text(text) => return serde_json::from_str(&text).map_err(|e| format!("invalid JSON-RPC message: {}", e)),
+                Message::Binary(bytes) => {
+                    return serde_json::from_slice(&bytes).map_err(|e| format!("invalid JSON-RPC message: {}", e))
+                }
+                Message::Close(_) => return Err("WebSocket closed".to_string()),
+                // tungstenite answers pings itself
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+            }
+        }
+    }
+
+    /// Close the connection
+    pub async fn close(mut self) {
+        let _ = self.stream.close(None).await;
+    }
+}